@@ -0,0 +1,59 @@
+//! Generates the solution registry of the aggregate `solutions` binary.
+//!
+//! Every solution in `src/bin/<year>_<day>.rs` is included as a module, the `solution!` macro
+//! exposes its parts as a `SOLUTION` constant.
+
+use std::{env, fs, path::Path};
+
+fn main() {
+    println!("cargo::rerun-if-changed=src/bin");
+
+    let manifest_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let bin_dir = Path::new(&manifest_dir).join("src").join("bin");
+
+    let mut names: Vec<String> = fs::read_dir(&bin_dir)
+        .unwrap()
+        .filter_map(|entry| {
+            let name = entry.ok()?.file_name().into_string().ok()?;
+            let stem = name.strip_suffix(".rs")?;
+            is_solution_name(stem).then(|| stem.to_string())
+        })
+        .collect();
+    names.sort_unstable();
+
+    let mut modules = String::new();
+    let mut entries = String::new();
+
+    for name in &names {
+        let path = bin_dir.join(format!("{name}.rs"));
+        // solutions define their own global allocator when profiling, which can only exist once.
+        modules.push_str(&format!(
+            "#[cfg(not(feature = \"dhat-heap\"))]\n#[allow(dead_code)]\n#[path = {:?}]\nmod y{name};\n\n",
+            path.display().to_string()
+        ));
+        entries.push_str(&format!(
+            "        #[cfg(not(feature = \"dhat-heap\"))]\n        y{name}::SOLUTION,\n"
+        ));
+    }
+
+    let registry = format!(
+        "{modules}/// Every solution in `src/bin`, sorted by puzzle.\n\
+        fn solutions() -> Vec<advent_of_code::template::registry::Solution> {{\n    \
+            vec![\n{entries}    ]\n\
+        }}\n"
+    );
+
+    let out_path = Path::new(&env::var("OUT_DIR").unwrap()).join("solutions.rs");
+    fs::write(out_path, registry).unwrap();
+}
+
+/// Matches solution binary names of the form `<year>_<day>`, e.g. `2025_01`.
+fn is_solution_name(name: &str) -> bool {
+    let bytes = name.as_bytes();
+    bytes.len() == 7
+        && bytes[4] == b'_'
+        && bytes
+            .iter()
+            .enumerate()
+            .all(|(i, b)| i == 4 || b.is_ascii_digit())
+}
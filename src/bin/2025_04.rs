@@ -0,0 +1,67 @@
+use advent_of_code::grid::Grid;
+
+advent_of_code::solution!(4);
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let grid = Grid::from_str_chars(input);
+    let mut result: u64 = 0;
+    grid.coords()
+        .filter(|(r, c)| grid.get(*r, *c) == Some(&'@'))
+        .for_each(|(r, c)| {
+            let neighbors = grid
+                .neighbors8(r, c)
+                .filter(|(r, c)| grid.get(*r, *c) == Some(&'@'))
+                .count() as u64;
+            if neighbors < 4 {
+                result += 1
+            }
+        });
+    Some(result)
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let mut grid = Grid::from_str_chars(input);
+    let mut result = 0u64;
+
+    let mut removable = Vec::<(usize, usize)>::new();
+    loop {
+        removable.clear();
+        for (r, c) in grid.coords() {
+            if grid.get(r, c) == Some(&'@') {
+                let neighbors = grid
+                    .neighbors8(r, c)
+                    .filter(|&(nr, nc)| grid.get(nr, nc) == Some(&'@'))
+                    .count();
+                if neighbors < 4 {
+                    result += 1;
+                    removable.push((r, c));
+                }
+            }
+        }
+
+        if removable.is_empty() {
+            break;
+        }
+        for (r, c) in &removable {
+            *grid.get_mut(*r, *c).unwrap() = '.';
+        }
+    }
+    Some(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(13));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(43));
+    }
+}
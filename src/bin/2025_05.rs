@@ -0,0 +1,64 @@
+use advent_of_code::intervals::IntervalSet;
+
+advent_of_code::solution!(5, parse);
+
+pub struct Inventory {
+    fresh: IntervalSet<u64>,
+    ingredients: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Inventory {
+    let (ranges_s, ingredients_s) = input.split_once("\n\n").unwrap_or((input, ""));
+
+    let fresh = ranges_s
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|line| {
+            let (a, b) = line.split_once('-').expect("range a-b");
+            let low: u64 = a.trim().parse().unwrap();
+            let high: u64 = b.trim().parse().unwrap();
+            low..=high
+        })
+        .collect();
+
+    let ingredients = ingredients_s
+        .lines()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.trim().parse::<u64>().unwrap())
+        .collect();
+
+    Inventory { fresh, ingredients }
+}
+
+pub fn part_one(inventory: &Inventory) -> Option<u64> {
+    let count = inventory
+        .ingredients
+        .iter()
+        .filter(|ingredient| inventory.fresh.contains(**ingredient))
+        .count() as u64;
+
+    Some(count)
+}
+
+pub fn part_two(inventory: &Inventory) -> Option<u64> {
+    Some(inventory.fresh.covered_len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_one(&parse(&input));
+        assert_eq!(result, Some(3));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_two(&parse(&input));
+        assert_eq!(result, Some(14));
+    }
+}
@@ -1,118 +1,6 @@
-advent_of_code::solution!(7);
-
-#[derive(Clone, Debug, PartialEq, Eq)]
-pub struct Grid<T> {
-    width: usize,
-    height: usize,
-    data: Vec<T>,
-}
-
-impl<T> Grid<T> {
-    pub fn new(width: usize, height: usize, fill: T) -> Self
-    where
-        T: Clone,
-    {
-        Self {
-            width,
-            height,
-            data: vec![fill; width * height],
-        }
-    }
-
-    pub fn from_vec(width: usize, height: usize, data: Vec<T>) -> Self {
-        assert_eq!(data.len(), width * height);
-        Self {
-            width,
-            height,
-            data,
-        }
-    }
+use advent_of_code::grid::Grid;
 
-    #[inline]
-    pub fn width(&self) -> usize {
-        self.width
-    }
-    #[inline]
-    pub fn height(&self) -> usize {
-        self.height
-    }
-
-    #[inline]
-    fn idx(&self, r: usize, c: usize) -> usize {
-        r * self.width + c
-    }
-
-    #[inline]
-    pub fn in_bounds(&self, r: isize, c: isize) -> bool {
-        r >= 0 && c >= 0 && (r as usize) < self.height && (c as usize) < self.width
-    }
-
-    #[inline]
-    pub fn get(&self, r: usize, c: usize) -> Option<&T> {
-        if r < self.height && c < self.width {
-            Some(&self.data[self.idx(r, c)])
-        } else {
-            None
-        }
-    }
-
-    #[inline]
-    pub fn get_mut(&mut self, r: usize, c: usize) -> Option<&mut T> {
-        if r < self.height && c < self.width {
-            let idx = self.idx(r, c);
-            Some(&mut self.data[idx])
-        } else {
-            None
-        }
-    }
-
-    /// Iterate over all coordinates row-major: (row, col)
-    pub fn coords(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
-        (0..self.height).flat_map(move |r| (0..self.width).map(move |c| (r, c)))
-    }
-
-    pub fn coords_rev(&self) -> impl DoubleEndedIterator<Item = (usize, usize)> + '_ {
-        (0..self.height - 1)
-            .rev()
-            .flat_map(move |r| (0..self.width - 1).rev().map(move |c| (r, c)))
-    }
-
-    /// 8-direction neighbor coordinates around (r, c)
-    pub fn neighbors8(&self, r: usize, c: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
-        const OFFSETS: [(isize, isize); 8] = [
-            (-1, -1),
-            (-1, 0),
-            (-1, 1),
-            (0, -1),
-            (0, 1),
-            (1, -1),
-            (1, 0),
-            (1, 1),
-        ];
-        let r = r as isize;
-        let c = c as isize;
-        OFFSETS
-            .into_iter()
-            .map(move |(dr, dc)| (r + dr, c + dc))
-            .filter(move |&(nr, nc)| self.in_bounds(nr, nc))
-            .map(|(nr, nc)| (nr as usize, nc as usize))
-    }
-}
-
-impl Grid<char> {
-    /// Construct a `Grid<char>` from a multi-line string
-    pub fn from_str_chars(s: &str) -> Self {
-        let lines: Vec<&str> = s.lines().collect();
-        let height = lines.len();
-        let width = lines.first().map(|l| l.len()).unwrap_or(0);
-
-        let mut data = Vec::with_capacity(width * height);
-        for line in lines {
-            data.extend(line.chars());
-        }
-        Grid::from_vec(width, height, data)
-    }
-}
+advent_of_code::solution!(7);
 
 pub fn part_one(input: &str) -> Option<u64> {
     let mut grid = Grid::from_str_chars(input);
@@ -170,15 +58,15 @@ pub fn part_two(input: &str) -> Option<u64> {
     // widths/heights are available via the grid; no local copies needed
 
     // Memoization table for in-bounds cells only
-    let mut memo: Vec<Option<u64>> = vec![None; grid.width * grid.height];
+    let mut memo: Vec<Option<u64>> = vec![None; grid.width() * grid.height()];
 
     fn idx(width: usize, r: usize, c: usize) -> usize {
         r * width + c
     }
 
     fn count(grid: &Grid<char>, memo: &mut Vec<Option<u64>>, r: isize, c: isize) -> u64 {
-        let width = grid.width as isize;
-        let height = grid.height as isize;
+        let width = grid.width() as isize;
+        let height = grid.height() as isize;
 
         // Exiting the manifold counts as one completed timeline
         if r >= height {
@@ -191,7 +79,7 @@ pub fn part_two(input: &str) -> Option<u64> {
         let ru = r as usize;
         let cu = c as usize;
 
-        let midx = idx(grid.width, ru, cu);
+        let midx = idx(grid.width(), ru, cu);
         if let Some(v) = memo[midx] {
             return v;
         }
@@ -221,13 +109,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let result = part_one(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(21));
     }
 
     #[test]
     fn test_part_two() {
-        let result = part_two(&advent_of_code::template::read_file("examples", DAY));
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
         assert_eq!(result, Some(40));
     }
 }
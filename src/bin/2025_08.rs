@@ -0,0 +1,59 @@
+use advent_of_code::spatial::{Point3, closest_pairs};
+use advent_of_code::template::context::Context;
+use advent_of_code::union_find::UnionFind;
+
+advent_of_code::solution!(8, parse);
+
+pub struct Playground {
+    boxes: Vec<Point3>,
+}
+
+pub fn parse(input: &str) -> Playground {
+    let boxes = input.lines().map(|line| line.parse().unwrap()).collect();
+    Playground { boxes }
+}
+
+pub fn part_one(playground: &Playground, context: &Context) -> Option<u64> {
+    let points = &playground.boxes;
+    let mut disjoint_set = UnionFind::new(points.len());
+    let connections = context.get_or("connections", 1000);
+    for (box1, box2, _) in closest_pairs(points).take(connections) {
+        disjoint_set.union(box1, box2);
+    }
+    let mut junction_box_sizes: Vec<usize> = disjoint_set
+        .components()
+        .map(|component| component.len())
+        .collect();
+    junction_box_sizes.sort_by(|a, b| b.cmp(a));
+    Some(junction_box_sizes[0] as u64 * junction_box_sizes[1] as u64 * junction_box_sizes[2] as u64)
+}
+
+pub fn part_two(playground: &Playground) -> Option<u64> {
+    let points = &playground.boxes;
+    let mut disjoint_set = UnionFind::new(points.len());
+    let (box1, box2, _) = closest_pairs(points).find(|&(box1, box2, _)| {
+        disjoint_set.union(box1, box2) && disjoint_set.component_count() == 1
+    })?;
+    Some((points[box1].x * points[box2].x) as u64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[test]
+    fn test_part_one() {
+        let context = Context::new(HashMap::from([("connections".into(), "10".into())]));
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_one(&parse(&input), &context);
+        assert_eq!(result, Some(40));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_two(&parse(&input));
+        assert_eq!(result, Some(25272));
+    }
+}
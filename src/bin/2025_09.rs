@@ -0,0 +1,48 @@
+use advent_of_code::geometry::{Rect, RectilinearPolygon};
+use advent_of_code::spatial::Point2;
+
+advent_of_code::solution!(9);
+
+fn parse_input(input: &str) -> Vec<Point2> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+/// Every rectangle with two red tiles as opposite corners.
+fn rectangles(red_tiles: &[Point2]) -> impl Iterator<Item = Rect> + '_ {
+    red_tiles.iter().enumerate().flat_map(move |(i, &a)| {
+        red_tiles[i + 1..]
+            .iter()
+            .map(move |&b| Rect::from_corners(a, b))
+    })
+}
+
+pub fn part_one(input: &str) -> Option<u64> {
+    let red_tiles = parse_input(input);
+    rectangles(&red_tiles).map(|rect| rect.area() as u64).max()
+}
+
+pub fn part_two(input: &str) -> Option<u64> {
+    let red_tiles = parse_input(input);
+    let floor = RectilinearPolygon::new(red_tiles.clone()).compress();
+    rectangles(&red_tiles)
+        .filter(|rect| floor.contains_rect(rect))
+        .map(|rect| rect.area() as u64)
+        .max()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let result = part_one(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(50));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let result = part_two(&advent_of_code::template::read_file("examples", PUZZLE));
+        assert_eq!(result, Some(24));
+    }
+}
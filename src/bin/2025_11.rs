@@ -0,0 +1,50 @@
+use advent_of_code::graph::Graph;
+
+advent_of_code::solution!(11, parse);
+
+const START: &str = "you";
+const END: &str = "out";
+const SVR: &str = "svr";
+const DAC: &str = "dac";
+const FFT: &str = "fft";
+
+pub fn parse(input: &str) -> Graph<&str> {
+    let mut graph = Graph::new();
+    for line in input.lines() {
+        let (start, edges) = line.split_once(":").unwrap();
+        for edge in edges.trim().split(' ') {
+            graph.add_edge(start, edge);
+        }
+    }
+    graph
+}
+
+pub fn part_one(graph: &Graph<&str>) -> Option<u64> {
+    graph.count_paths(graph.id(&START)?, graph.id(&END)?).ok()
+}
+
+pub fn part_two(graph: &Graph<&str>) -> Option<u64> {
+    let waypoints = [graph.id(&DAC)?, graph.id(&FFT)?];
+    graph
+        .count_paths_through(graph.id(&SVR)?, graph.id(&END)?, &waypoints)
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_one(&parse(&input));
+        assert_eq!(result, Some(5));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::template::read_file("examples", PUZZLE);
+        let result = part_two(&parse(&input));
+        assert_eq!(result, Some(2));
+    }
+}
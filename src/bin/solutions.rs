@@ -0,0 +1,7 @@
+//! Aggregate binary that runs every solution in-process. Used by `cargo all`, `cargo time` and `cargo verify`.
+
+include!(concat!(env!("OUT_DIR"), "/solutions.rs"));
+
+fn main() {
+    advent_of_code::template::registry::main(&solutions());
+}
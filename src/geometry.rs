@@ -0,0 +1,402 @@
+//! Rectangles and rectilinear polygons on the integer grid, with coordinate compression and 2D prefix sums to query
+//! them quickly.
+//!
+//! Shapes are sets of lattice points (tiles): a polygon contains its boundary, and rectangles include both corners.
+use std::ops::RangeInclusive;
+
+use crate::grid::Grid;
+use crate::spatial::Point2;
+
+/// An axis-aligned rectangle including its border, i.e. all points between `min` and `max`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Rect {
+    pub min: Point2,
+    pub max: Point2,
+}
+
+impl Rect {
+    /// The rectangle spanned by two opposite corners, in any order.
+    pub fn from_corners(a: Point2, b: Point2) -> Self {
+        Self {
+            min: Point2::new(a.x.min(b.x), a.y.min(b.y)),
+            max: Point2::new(a.x.max(b.x), a.y.max(b.y)),
+        }
+    }
+
+    #[inline]
+    pub fn width(&self) -> i64 {
+        self.max.x - self.min.x + 1
+    }
+
+    #[inline]
+    pub fn height(&self) -> i64 {
+        self.max.y - self.min.y + 1
+    }
+
+    /// Number of points in the rectangle.
+    #[inline]
+    pub fn area(&self) -> i64 {
+        self.width() * self.height()
+    }
+
+    pub fn contains(&self, point: Point2) -> bool {
+        (self.min.x..=self.max.x).contains(&point.x) && (self.min.y..=self.max.y).contains(&point.y)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A simple polygon whose consecutive vertices share a row or column, closed from the last vertex back to the first.
+#[derive(Clone, Debug)]
+pub struct RectilinearPolygon {
+    vertices: Vec<Point2>,
+}
+
+impl RectilinearPolygon {
+    /// # Panics
+    /// Panics if two consecutive vertices share neither their `x` nor their `y` coordinate.
+    pub fn new(vertices: Vec<Point2>) -> Self {
+        let polygon = Self { vertices };
+        for (a, b) in polygon.edges() {
+            assert!(
+                a.x == b.x || a.y == b.y,
+                "edge from {a:?} to {b:?} is not axis-aligned"
+            );
+        }
+        polygon
+    }
+
+    pub fn vertices(&self) -> &[Point2] {
+        &self.vertices
+    }
+
+    /// Iterate over all edges as pairs of their end points, including the closing one.
+    pub fn edges(&self) -> impl Iterator<Item = (Point2, Point2)> + '_ {
+        self.vertices
+            .iter()
+            .copied()
+            .zip(self.vertices.iter().copied().cycle().skip(1))
+    }
+
+    /// Whether `point` lies on an edge.
+    pub fn on_boundary(&self, point: Point2) -> bool {
+        self.edges()
+            .any(|(a, b)| Rect::from_corners(a, b).contains(point))
+    }
+
+    /// Whether `point` lies inside or on the boundary. Takes `O(n)`, see [`RectilinearPolygon::compress`] for many
+    /// queries.
+    pub fn contains(&self, point: Point2) -> bool {
+        if self.on_boundary(point) {
+            return true;
+        }
+
+        // cast a ray towards positive x and count the vertical edges it crosses. Edges are half-open in y, so a ray
+        // through a vertex is only counted once.
+        let crossings = self
+            .edges()
+            .filter(|(a, b)| a.x == b.x && a.x > point.x)
+            .filter(|(a, b)| (a.y.min(b.y)..a.y.max(b.y)).contains(&point.y))
+            .count();
+        crossings % 2 == 1
+    }
+
+    /// Builds a compressed mask of the polygon to test points and rectangles in `O(log n)`.
+    pub fn compress(&self) -> CompressedPolygon {
+        let xs = Compression::new(self.vertices.iter().map(|v| v.x));
+        let ys = Compression::new(self.vertices.iter().map(|v| v.y));
+
+        // one cell of padding on every side, so the outside is connected around the polygon.
+        let mut boundary = Grid::new(xs.cell_count() + 2, ys.cell_count() + 2, false);
+        for (a, b) in self.edges() {
+            let Rect { min, max } = Rect::from_corners(a, b);
+            for row in ys.cell_of(min.y).unwrap()..=ys.cell_of(max.y).unwrap() {
+                for col in xs.cell_of(min.x).unwrap()..=xs.cell_of(max.x).unwrap() {
+                    *boundary.get_mut(row + 1, col + 1).unwrap() = true;
+                }
+            }
+        }
+
+        // outside cells are weighted by the tiles they cover. Gaps between adjacent coordinates cover none, so the
+        // outside reaching into them doesn't exclude any tile.
+        let len = |compression: &Compression, padded_cell: usize| match padded_cell {
+            0 => 1,
+            cell if cell > compression.cell_count() => 1,
+            cell => compression.cell_len(cell - 1),
+        };
+        let mut outside = Grid::new(boundary.width(), boundary.height(), 0);
+        for (row, col) in boundary.flood_fill((0, 0), |is_boundary| !is_boundary) {
+            *outside.get_mut(row, col).unwrap() = len(&ys, row) * len(&xs, col);
+        }
+
+        CompressedPolygon {
+            outside: PrefixSums::new(&outside, |&tiles| tiles),
+            xs,
+            ys,
+        }
+    }
+}
+
+/// A [`RectilinearPolygon`] on a compressed grid, built with [`RectilinearPolygon::compress`].
+#[derive(Clone, Debug)]
+pub struct CompressedPolygon {
+    xs: Compression,
+    ys: Compression,
+    /// Counts of outside tiles per cell, padded by one cell on every side.
+    outside: PrefixSums,
+}
+
+impl CompressedPolygon {
+    /// Whether `point` lies inside or on the boundary.
+    pub fn contains(&self, point: Point2) -> bool {
+        self.contains_rect(&Rect::from_corners(point, point))
+    }
+
+    /// Whether every point of `rect` lies inside or on the boundary.
+    pub fn contains_rect(&self, rect: &Rect) -> bool {
+        let cells = |compression: &Compression, min, max| -> Option<RangeInclusive<usize>> {
+            Some(compression.cell_of(min)? + 1..=compression.cell_of(max)? + 1)
+        };
+
+        match (
+            cells(&self.ys, rect.min.y, rect.max.y),
+            cells(&self.xs, rect.min.x, rect.max.x),
+        ) {
+            (Some(rows), Some(cols)) => self.outside.sum(rows, cols) == 0,
+            // the polygon doesn't reach beyond its outermost vertices.
+            _ => false,
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Maps a sparse set of coordinates to dense cells.
+///
+/// Every distinct value gets a cell, and so does every gap between two consecutive values: value `i` is cell `2 * i`
+/// and the gap after it is cell `2 * i + 1`. All coordinates within a gap behave the same for shapes whose corners
+/// are among the values, so one cell per gap is enough.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Compression {
+    values: Vec<i64>,
+}
+
+impl Compression {
+    pub fn new(values: impl IntoIterator<Item = i64>) -> Self {
+        let mut values: Vec<i64> = values.into_iter().collect();
+        values.sort_unstable();
+        values.dedup();
+        Self { values }
+    }
+
+    /// The distinct values in ascending order.
+    pub fn values(&self) -> &[i64] {
+        &self.values
+    }
+
+    /// Position of `value` among the distinct values, `None` if it isn't one of them.
+    pub fn index_of(&self, value: i64) -> Option<usize> {
+        self.values.binary_search(&value).ok()
+    }
+
+    /// Number of cells, counting values and the gaps between them.
+    pub fn cell_count(&self) -> usize {
+        (2 * self.values.len()).saturating_sub(1)
+    }
+
+    /// The cell of `value`, `None` if it is outside of the range of values.
+    pub fn cell_of(&self, value: i64) -> Option<usize> {
+        match self.values.binary_search(&value) {
+            Ok(index) => Some(2 * index),
+            Err(index) if index > 0 && index < self.values.len() => Some(2 * index - 1),
+            Err(_) => None,
+        }
+    }
+
+    /// Number of coordinates covered by `cell`, 0 for the gap between adjacent values.
+    ///
+    /// # Panics
+    /// Panics if `cell` is out of bounds.
+    pub fn cell_len(&self, cell: usize) -> i64 {
+        assert!(cell < self.cell_count(), "cell {cell} out of bounds");
+        if cell.is_multiple_of(2) {
+            1
+        } else {
+            self.values[cell / 2 + 1] - self.values[cell / 2] - 1
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Sums over any rectangle of a grid in constant time.
+#[derive(Clone, Debug)]
+pub struct PrefixSums {
+    /// `sums[r][c]` holds the sum of all cells above and left of `(r, c)`, so row and column 0 are all zeros.
+    sums: Grid<i64>,
+}
+
+impl PrefixSums {
+    /// Precomputes the sums of `value` of the cells of `grid`.
+    pub fn new<T>(grid: &Grid<T>, value: impl Fn(&T) -> i64) -> Self {
+        let mut sums = Grid::new(grid.width() + 1, grid.height() + 1, 0);
+        for ((r, c), cell) in grid.iter() {
+            let sum = value(cell) + sums.get(r, c + 1).unwrap() + sums.get(r + 1, c).unwrap()
+                - sums.get(r, c).unwrap();
+            *sums.get_mut(r + 1, c + 1).unwrap() = sum;
+        }
+        Self { sums }
+    }
+
+    /// Sum of the cells in `rows` and `cols`. Empty ranges sum to 0.
+    ///
+    /// # Panics
+    /// Panics if the ranges reach beyond the grid.
+    pub fn sum(&self, rows: RangeInclusive<usize>, cols: RangeInclusive<usize>) -> i64 {
+        if rows.is_empty() || cols.is_empty() {
+            return 0;
+        }
+        let (top, bottom) = (*rows.start(), rows.end() + 1);
+        let (left, right) = (*cols.start(), cols.end() + 1);
+        let at = |r, c| *self.sums.get(r, c).expect("range out of bounds");
+        at(bottom, right) - at(top, right) - at(bottom, left) + at(top, left)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Compression, PrefixSums, Rect, RectilinearPolygon};
+    use crate::grid::Grid;
+    use crate::spatial::Point2;
+
+    /// A U shape, open towards the top:
+    ///
+    /// ```text
+    /// #.#
+    /// #.#
+    /// ###
+    /// ```
+    fn u_shape() -> RectilinearPolygon {
+        RectilinearPolygon::new(
+            [
+                (0, 0),
+                (2, 0),
+                (2, 6),
+                (4, 6),
+                (4, 0),
+                (6, 0),
+                (6, 8),
+                (0, 8),
+            ]
+            .map(|(x, y)| Point2::new(x, y))
+            .to_vec(),
+        )
+    }
+
+    #[test]
+    fn measures_rectangles() {
+        let rect = Rect::from_corners(Point2::new(11, 7), Point2::new(2, 3));
+        assert_eq!(rect.min, Point2::new(2, 3));
+        assert_eq!(rect.area(), 50);
+        assert!(rect.contains(Point2::new(11, 3)));
+        assert!(!rect.contains(Point2::new(12, 3)));
+    }
+
+    #[test]
+    fn tests_points_in_polygons() {
+        let polygon = u_shape();
+        assert!(polygon.on_boundary(Point2::new(3, 6)));
+        assert!(polygon.contains(Point2::new(1, 3)));
+        assert!(polygon.contains(Point2::new(3, 7)));
+        assert!(!polygon.contains(Point2::new(3, 5)));
+        assert!(!polygon.contains(Point2::new(7, 3)));
+        assert!(!polygon.contains(Point2::new(-1, 8)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_diagonal_edges() {
+        RectilinearPolygon::new(vec![
+            Point2::new(0, 0),
+            Point2::new(2, 2),
+            Point2::new(0, 2),
+        ]);
+    }
+
+    #[test]
+    fn compresses_coordinates() {
+        let compression = Compression::new([10, 3, 7, 3, 8]);
+        assert_eq!(compression.values(), &[3, 7, 8, 10]);
+        assert_eq!(compression.cell_count(), 7);
+        assert_eq!(compression.index_of(8), Some(2));
+        assert_eq!(compression.index_of(9), None);
+        assert_eq!(compression.cell_of(3), Some(0));
+        assert_eq!(compression.cell_of(5), Some(1));
+        assert_eq!(compression.cell_of(9), Some(5));
+        assert_eq!(compression.cell_of(11), None);
+        assert_eq!(compression.cell_len(1), 3);
+        assert_eq!(compression.cell_len(3), 0);
+    }
+
+    #[test]
+    fn sums_rectangles() {
+        let grid = Grid::from_str_digits("123\n456\n789");
+        let sums = PrefixSums::new(&grid, |&digit| i64::from(digit));
+        assert_eq!(sums.sum(0..=2, 0..=2), 45);
+        assert_eq!(sums.sum(1..=2, 1..=2), 28);
+        assert_eq!(sums.sum(0..=0, 2..=2), 3);
+        #[allow(clippy::reversed_empty_ranges)]
+        let empty = sums.sum(2..=1, 0..=2);
+        assert_eq!(empty, 0);
+    }
+
+    #[test]
+    fn compressed_polygons_match_the_polygon() {
+        let polygon = u_shape();
+        let compressed = polygon.compress();
+
+        for x in -1..=7 {
+            for y in -1..=9 {
+                let point = Point2::new(x, y);
+                assert_eq!(
+                    compressed.contains(point),
+                    polygon.contains(point),
+                    "{point:?}"
+                );
+            }
+        }
+
+        let rect = |a: (i64, i64), b: (i64, i64)| {
+            Rect::from_corners(Point2::new(a.0, a.1), Point2::new(b.0, b.1))
+        };
+        assert!(compressed.contains_rect(&rect((0, 0), (2, 8))));
+        assert!(compressed.contains_rect(&rect((0, 6), (6, 8))));
+        assert!(!compressed.contains_rect(&rect((0, 5), (6, 8))));
+        assert!(!compressed.contains_rect(&rect((0, 0), (6, 8))));
+    }
+
+    #[test]
+    fn ignores_gaps_without_tiles() {
+        // a notch between the adjacent columns 2 and 3 covers no tiles.
+        let polygon = RectilinearPolygon::new(
+            [
+                (0, 0),
+                (2, 0),
+                (2, 5),
+                (3, 5),
+                (3, 0),
+                (5, 0),
+                (5, 8),
+                (0, 8),
+            ]
+            .map(|(x, y)| Point2::new(x, y))
+            .to_vec(),
+        );
+        let rect = Rect::from_corners(Point2::new(0, 0), Point2::new(5, 8));
+
+        assert!((0..=5).all(|x| (0..=8).all(|y| polygon.contains(Point2::new(x, y)))));
+        assert!(polygon.compress().contains_rect(&rect));
+    }
+}
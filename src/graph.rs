@@ -0,0 +1,457 @@
+//! Directed graphs over interned node names, and searches over implicit graphs given by a successor function.
+use std::cmp::{Ordering, Reverse};
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::error::Error;
+use std::fmt::Display;
+use std::hash::Hash;
+
+/// Dense id of an interned node, usable as an index.
+pub type NodeId = usize;
+
+/// Returned for graphs that contain a cycle where a DAG is required.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    /// A node on or behind the cycle.
+    pub node: NodeId,
+}
+
+impl Error for Cycle {}
+
+impl Display for Cycle {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "the graph contains a cycle through node {}", self.node)
+    }
+}
+
+/// A directed graph with weighted edges. Nodes are named by `K` and interned to [`NodeId`]s on first use.
+#[derive(Clone, Debug)]
+pub struct Graph<K> {
+    ids: HashMap<K, NodeId>,
+    names: Vec<K>,
+    edges: Vec<Vec<(NodeId, u64)>>,
+}
+
+impl<K> Default for Graph<K> {
+    fn default() -> Self {
+        Self {
+            ids: HashMap::new(),
+            names: vec![],
+            edges: vec![],
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> Graph<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of nodes.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Interns `name`, returns the id of the new or existing node.
+    pub fn add_node(&mut self, name: K) -> NodeId {
+        if let Some(&id) = self.ids.get(&name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.clone(), id);
+        self.names.push(name);
+        self.edges.push(vec![]);
+        id
+    }
+
+    /// Adds an edge of weight 1, interning both nodes.
+    pub fn add_edge(&mut self, from: K, to: K) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    /// Adds an edge of `weight`, interning both nodes.
+    pub fn add_weighted_edge(&mut self, from: K, to: K, weight: u64) {
+        let from = self.add_node(from);
+        let to = self.add_node(to);
+        self.edges[from].push((to, weight));
+    }
+
+    /// The id of `name`, `None` if there is no such node.
+    pub fn id(&self, name: &K) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    /// The name of `id`.
+    ///
+    /// # Panics
+    /// Panics if `id` is out of bounds.
+    pub fn name(&self, id: NodeId) -> &K {
+        &self.names[id]
+    }
+
+    /// Outgoing edges of `id` as `(target, weight)`, in insertion order.
+    pub fn edges(&self, id: NodeId) -> impl Iterator<Item = (NodeId, u64)> + '_ {
+        self.edges[id].iter().copied()
+    }
+
+    /// Targets of the outgoing edges of `id`, in insertion order.
+    pub fn successors(&self, id: NodeId) -> impl Iterator<Item = NodeId> + '_ {
+        self.edges[id].iter().map(|&(to, _)| to)
+    }
+
+    /// Every node ordered so that edges only point forward.
+    pub fn topological_sort(&self) -> Result<Vec<NodeId>, Cycle> {
+        self.topological_order(&vec![true; self.len()])
+    }
+
+    /// Number of distinct paths from `from` to `to`.
+    ///
+    /// Returns an error if a cycle is reachable from `from`, as there could be infinitely many paths.
+    pub fn count_paths(&self, from: NodeId, to: NodeId) -> Result<u64, Cycle> {
+        self.count_paths_through(from, to, &[])
+    }
+
+    /// Number of distinct paths from `from` to `to` that visit every node of `waypoints`, in any order.
+    ///
+    /// Counts are kept per subset of visited waypoints, so the work grows with `2^waypoints.len()`.
+    /// Returns an error if a cycle is reachable from `from`, as there could be infinitely many paths.
+    pub fn count_paths_through(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        waypoints: &[NodeId],
+    ) -> Result<u64, Cycle> {
+        let mask_of = |node: NodeId| {
+            waypoints
+                .iter()
+                .enumerate()
+                .filter(|&(_, &waypoint)| waypoint == node)
+                .fold(0, |mask, (i, _)| mask | 1 << i)
+        };
+        let masks = 1 << waypoints.len();
+
+        let order = self.topological_order(&self.reachable(from))?;
+
+        // counts[node * masks + mask]: paths from `from` to `node` that visited the waypoints in `mask`.
+        let mut counts = vec![0_u64; self.len() * masks];
+        counts[from * masks + mask_of(from)] = 1;
+
+        for node in order {
+            for mask in 0..masks {
+                let count = counts[node * masks + mask];
+                if count == 0 {
+                    continue;
+                }
+                for next in self.successors(node) {
+                    counts[next * masks + (mask | mask_of(next))] += count;
+                }
+            }
+        }
+
+        Ok(counts[to * masks + masks - 1])
+    }
+
+    /// Marks every node reachable from `start`, including `start`.
+    fn reachable(&self, start: NodeId) -> Vec<bool> {
+        let mut seen = vec![false; self.len()];
+        seen[start] = true;
+        let mut stack = vec![start];
+        while let Some(node) = stack.pop() {
+            for next in self.successors(node) {
+                if !seen[next] {
+                    seen[next] = true;
+                    stack.push(next);
+                }
+            }
+        }
+        seen
+    }
+
+    /// Kahn's algorithm over the nodes marked in `included`, which must be closed under successors.
+    fn topological_order(&self, included: &[bool]) -> Result<Vec<NodeId>, Cycle> {
+        let mut in_degree = vec![0_usize; self.len()];
+        for node in (0..self.len()).filter(|&node| included[node]) {
+            for next in self.successors(node) {
+                in_degree[next] += 1;
+            }
+        }
+
+        let mut queue: VecDeque<NodeId> = (0..self.len())
+            .filter(|&node| included[node] && in_degree[node] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(self.len());
+
+        while let Some(node) = queue.pop_front() {
+            order.push(node);
+            for next in self.successors(node) {
+                in_degree[next] -= 1;
+                if in_degree[next] == 0 {
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        // nodes on a cycle never lose all their incoming edges.
+        match (0..self.len()).find(|&node| included[node] && in_degree[node] > 0) {
+            Some(node) => Err(Cycle { node }),
+            None => Ok(order),
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Fewest steps from `start` to every reachable node.
+pub fn bfs<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, usize>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((node, distance)) = queue.pop_front() {
+        for next in successors(&node) {
+            if !distances.contains_key(&next) {
+                distances.insert(next.clone(), distance + 1);
+                queue.push_back((next, distance + 1));
+            }
+        }
+    }
+
+    distances
+}
+
+/// Lowest cost from `start` to every reachable node, given `(node, cost)` successors.
+pub fn dijkstra<N, I>(start: N, mut successors: impl FnMut(&N) -> I) -> HashMap<N, u64>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse(Entry {
+        priority: 0,
+        cost: 0,
+        node: start,
+    })]);
+
+    while let Some(Reverse(Entry { cost, node, .. })) = queue.pop() {
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                queue.push(Reverse(Entry {
+                    priority: next_cost,
+                    cost: next_cost,
+                    node: next,
+                }));
+            }
+        }
+    }
+
+    costs
+}
+
+/// Cheapest path from `start` to a node satisfying `is_goal`, with its cost.
+///
+/// `heuristic` estimates the remaining cost to a goal and must never overestimate it, else the path may not be the
+/// cheapest. With a heuristic of 0 this is Dijkstra's algorithm.
+pub fn astar<N, I>(
+    start: N,
+    mut is_goal: impl FnMut(&N) -> bool,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> u64,
+) -> Option<(Vec<N>, u64)>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, u64)>,
+{
+    let mut costs = HashMap::from([(start.clone(), 0)]);
+    let mut came_from: HashMap<N, N> = HashMap::new();
+    let mut queue = BinaryHeap::from([Reverse(Entry {
+        priority: heuristic(&start),
+        cost: 0,
+        node: start,
+    })]);
+
+    while let Some(Reverse(Entry { cost, node, .. })) = queue.pop() {
+        if is_goal(&node) {
+            let mut path = vec![node];
+            while let Some(previous) = came_from.get(path.last().unwrap()) {
+                path.push(previous.clone());
+            }
+            path.reverse();
+            return Some((path, cost));
+        }
+        if costs.get(&node).is_some_and(|&best| best < cost) {
+            continue;
+        }
+        for (next, step) in successors(&node) {
+            let next_cost = cost + step;
+            if costs.get(&next).is_none_or(|&best| next_cost < best) {
+                costs.insert(next.clone(), next_cost);
+                came_from.insert(next.clone(), node.clone());
+                queue.push(Reverse(Entry {
+                    priority: next_cost + heuristic(&next),
+                    cost: next_cost,
+                    node: next,
+                }));
+            }
+        }
+    }
+
+    None
+}
+
+/// A queued node, ordered by priority only so nodes need not be comparable.
+struct Entry<N> {
+    priority: u64,
+    cost: u64,
+    node: N,
+}
+
+impl<N> PartialEq for Entry<N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+
+impl<N> Eq for Entry<N> {}
+
+impl<N> PartialOrd for Entry<N> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<N> Ord for Entry<N> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.priority.cmp(&other.priority)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Cycle, Graph, astar, bfs, dijkstra};
+
+    fn graph(edges: &[(&'static str, &'static str)]) -> Graph<&'static str> {
+        let mut graph = Graph::new();
+        for &(from, to) in edges {
+            graph.add_edge(from, to);
+        }
+        graph
+    }
+
+    #[test]
+    fn interns_nodes() {
+        let mut graph = graph(&[("a", "b"), ("b", "c"), ("a", "c")]);
+        assert_eq!(graph.len(), 3);
+        assert_eq!(graph.add_node("b"), 1);
+        assert_eq!(graph.id(&"c"), Some(2));
+        assert_eq!(graph.id(&"x"), None);
+        assert_eq!(graph.name(0), &"a");
+        assert_eq!(graph.successors(0).collect::<Vec<_>>(), vec![1, 2]);
+    }
+
+    #[test]
+    fn sorts_topologically() {
+        let graph = graph(&[
+            ("shirt", "tie"),
+            ("tie", "jacket"),
+            ("pants", "shoes"),
+            ("pants", "jacket"),
+        ]);
+        let order = graph.topological_sort().unwrap();
+        let position = |name| order.iter().position(|&id| id == graph.id(&name).unwrap());
+
+        assert_eq!(order.len(), graph.len());
+        assert!(position("shirt") < position("tie"));
+        assert!(position("tie") < position("jacket"));
+        assert!(position("pants") < position("shoes"));
+    }
+
+    #[test]
+    fn detects_cycles() {
+        let graph = graph(&[("a", "b"), ("b", "c"), ("c", "b"), ("x", "a")]);
+        assert!(matches!(graph.topological_sort(), Err(Cycle { .. })));
+
+        let a = graph.id(&"a").unwrap();
+        let c = graph.id(&"c").unwrap();
+        assert!(graph.count_paths(a, c).is_err());
+    }
+
+    #[test]
+    fn counts_paths_through_waypoints() {
+        // two ways from s to m, and two ways from m to t.
+        let graph = graph(&[
+            ("s", "a"),
+            ("s", "b"),
+            ("a", "m"),
+            ("b", "m"),
+            ("m", "t"),
+            ("m", "c"),
+            ("c", "t"),
+            ("s", "t"),
+            // unreachable from s, so the cycle doesn't matter.
+            ("x", "y"),
+            ("y", "x"),
+        ]);
+        let id = |name| graph.id(&name).unwrap();
+
+        assert_eq!(graph.count_paths(id("s"), id("t")), Ok(5));
+        assert_eq!(
+            graph.count_paths_through(id("s"), id("t"), &[id("m")]),
+            Ok(4)
+        );
+        assert_eq!(
+            graph.count_paths_through(id("s"), id("t"), &[id("a"), id("c")]),
+            Ok(1)
+        );
+        assert_eq!(graph.count_paths(id("t"), id("s")), Ok(0));
+    }
+
+    #[test]
+    fn searches_implicit_graphs() {
+        // steps of +1 cost 1, doubling costs 3.
+        let successors = |&n: &u64| {
+            [(n + 1, 1), (n * 2, 3)]
+                .into_iter()
+                .filter(|&(n, _)| n <= 20)
+        };
+
+        let steps = bfs(1_u64, |&n| successors(&n).map(|(n, _)| n));
+        assert_eq!(steps[&16], 4);
+
+        let costs = dijkstra(1_u64, successors);
+        assert_eq!(costs[&16], 9);
+        assert_eq!(costs[&5], 4);
+
+        let (path, cost) = astar(1_u64, |&n| n == 16, successors, |&n| u64::from(n != 16)).unwrap();
+        assert_eq!(cost, 9);
+        assert_eq!(path.first(), Some(&1));
+        assert_eq!(path.last(), Some(&16));
+        assert_eq!(astar(1_u64, |&n| n == 40, successors, |_| 0), None);
+    }
+
+    #[test]
+    fn runs_on_graphs() {
+        let mut graph = Graph::new();
+        graph.add_weighted_edge("a", "b", 7);
+        graph.add_weighted_edge("a", "c", 2);
+        graph.add_weighted_edge("c", "b", 3);
+        let (a, b) = (graph.id(&"a").unwrap(), graph.id(&"b").unwrap());
+
+        assert_eq!(dijkstra(a, |&node| graph.edges(node))[&b], 5);
+        assert_eq!(bfs(a, |&node| graph.successors(node))[&b], 1);
+    }
+}
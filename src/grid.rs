@@ -0,0 +1,416 @@
+//! A dense, row-major 2D grid shared by grid-based puzzles.
+use std::collections::VecDeque;
+use std::fmt::Display;
+
+/// Offsets of the 4 orthogonal neighbors: up, left, right, down.
+const OFFSETS_4: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the 8 surrounding neighbors, row-major.
+const OFFSETS_8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// A `width` x `height` grid of values, addressed by `(row, col)`.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    data: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Creates a grid where every cell holds a copy of `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            data: vec![fill; width * height],
+        }
+    }
+
+    /// Creates a grid from row-major `data`.
+    ///
+    /// # Panics
+    /// Panics if `data` does not hold exactly `width * height` values.
+    pub fn from_vec(width: usize, height: usize, data: Vec<T>) -> Self {
+        assert_eq!(data.len(), width * height);
+        Self {
+            width,
+            height,
+            data,
+        }
+    }
+
+    /// Creates a grid by mapping every character of a multi-line string.
+    ///
+    /// # Panics
+    /// Panics if the lines are not all of the same length.
+    pub fn parse_with(s: &str, f: impl Fn(char) -> T) -> Self {
+        let mut width = 0;
+        let mut height = 0;
+        let mut data = Vec::with_capacity(s.len());
+
+        for line in s.lines() {
+            let len = data.len();
+            data.extend(line.chars().map(&f));
+            let line_width = data.len() - len;
+            if height == 0 {
+                width = line_width;
+            }
+            assert_eq!(
+                line_width, width,
+                "grid line {height} has an unexpected width"
+            );
+            height += 1;
+        }
+
+        Self::from_vec(width, height, data)
+    }
+
+    #[inline]
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    #[inline]
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    #[inline]
+    fn idx(&self, r: usize, c: usize) -> usize {
+        r * self.width + c
+    }
+
+    #[inline]
+    pub fn in_bounds(&self, r: isize, c: isize) -> bool {
+        r >= 0 && c >= 0 && (r as usize) < self.height && (c as usize) < self.width
+    }
+
+    #[inline]
+    pub fn get(&self, r: usize, c: usize) -> Option<&T> {
+        if r < self.height && c < self.width {
+            Some(&self.data[self.idx(r, c)])
+        } else {
+            None
+        }
+    }
+
+    #[inline]
+    pub fn get_mut(&mut self, r: usize, c: usize) -> Option<&mut T> {
+        if r < self.height && c < self.width {
+            let idx = self.idx(r, c);
+            Some(&mut self.data[idx])
+        } else {
+            None
+        }
+    }
+
+    /// Iterate over all coordinates row-major: (row, col)
+    pub fn coords(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..self.height).flat_map(move |r| (0..self.width).map(move |c| (r, c)))
+    }
+
+    /// Iterate over all cells row-major: ((row, col), value)
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> + '_ {
+        self.coords().zip(self.data.iter())
+    }
+
+    /// 4-direction neighbor coordinates around (r, c)
+    pub fn neighbors4(&self, r: usize, c: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(r, c, &OFFSETS_4)
+    }
+
+    /// 8-direction neighbor coordinates around (r, c)
+    pub fn neighbors8(&self, r: usize, c: usize) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.neighbors(r, c, &OFFSETS_8)
+    }
+
+    fn neighbors<'a>(
+        &'a self,
+        r: usize,
+        c: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        let r = r as isize;
+        let c = c as isize;
+        offsets
+            .iter()
+            .map(move |(dr, dc)| (r + dr, c + dc))
+            .filter(move |&(nr, nc)| self.in_bounds(nr, nc))
+            .map(|(nr, nc)| (nr as usize, nc as usize))
+    }
+
+    /// Returns row `r` as a slice.
+    ///
+    /// # Panics
+    /// Panics if `r` is out of bounds.
+    pub fn row(&self, r: usize) -> &[T] {
+        assert!(r < self.height, "row {r} out of bounds");
+        let start = self.idx(r, 0);
+        &self.data[start..start + self.width]
+    }
+
+    /// Iterate over all rows as slices, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> + '_ {
+        (0..self.height).map(move |r| self.row(r))
+    }
+
+    /// Iterate over the values of column `c`, top to bottom.
+    ///
+    /// # Panics
+    /// Panics if `c` is out of bounds.
+    pub fn column(&self, c: usize) -> impl Iterator<Item = &T> + '_ {
+        assert!(c < self.width, "column {c} out of bounds");
+        self.data.iter().skip(c).step_by(self.width)
+    }
+
+    /// Iterate over all columns, left to right.
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.width).map(move |c| self.column(c))
+    }
+
+    /// Returns the grid mirrored along its main diagonal.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |r, c| (c, r))
+    }
+
+    /// Returns the grid rotated by 90 degrees clockwise.
+    pub fn rotate_cw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let height = self.height;
+        self.remap(height, self.width, |r, c| (height - 1 - c, r))
+    }
+
+    /// Returns the grid rotated by 90 degrees counter-clockwise.
+    pub fn rotate_ccw(&self) -> Self
+    where
+        T: Clone,
+    {
+        let width = self.width;
+        self.remap(self.height, width, |r, c| (c, width - 1 - r))
+    }
+
+    /// Builds a `width` x `height` grid where cell (r, c) is copied from `source(r, c)` of `self`.
+    fn remap(
+        &self,
+        width: usize,
+        height: usize,
+        source: impl Fn(usize, usize) -> (usize, usize),
+    ) -> Self
+    where
+        T: Clone,
+    {
+        let data = (0..height)
+            .flat_map(|r| (0..width).map(move |c| (r, c)))
+            .map(|(r, c)| {
+                let (sr, sc) = source(r, c);
+                self.data[self.idx(sr, sc)].clone()
+            })
+            .collect();
+        Self::from_vec(width, height, data)
+    }
+
+    /// Coordinates of the region reachable from `start` through 4-direction steps
+    /// onto cells that satisfy `can_enter`, in BFS order.
+    ///
+    /// `start` is always part of the region if it is in bounds.
+    pub fn flood_fill(
+        &self,
+        start: (usize, usize),
+        can_enter: impl Fn(&T) -> bool,
+    ) -> Vec<(usize, usize)> {
+        let mut region = vec![];
+        if self.get(start.0, start.1).is_none() {
+            return region;
+        }
+
+        let mut seen = vec![false; self.data.len()];
+        let mut queue = VecDeque::from([start]);
+        seen[self.idx(start.0, start.1)] = true;
+
+        while let Some((r, c)) = queue.pop_front() {
+            region.push((r, c));
+            for (nr, nc) in self.neighbors4(r, c) {
+                let idx = self.idx(nr, nc);
+                if !seen[idx] && can_enter(&self.data[idx]) {
+                    seen[idx] = true;
+                    queue.push_back((nr, nc));
+                }
+            }
+        }
+
+        region
+    }
+
+    /// Coordinates of the first cell (row-major) holding `value`.
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(pos, _)| pos)
+    }
+
+    /// Coordinates of every cell (row-major) holding `value`.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, v)| *v == value)
+            .map(|(pos, _)| pos)
+    }
+}
+
+impl Grid<char> {
+    /// Construct a `Grid<char>` from a multi-line string
+    pub fn from_str_chars(s: &str) -> Self {
+        Self::parse_with(s, |ch| ch)
+    }
+}
+
+impl Grid<u8> {
+    /// Construct a `Grid<u8>` of raw bytes from a multi-line ASCII string
+    ///
+    /// # Panics
+    /// Panics if the string contains a character that is not ASCII.
+    pub fn from_str_bytes(s: &str) -> Self {
+        Self::parse_with(s, |ch| {
+            u8::try_from(ch)
+                .ok()
+                .filter(u8::is_ascii)
+                .unwrap_or_else(|| panic!("expected an ASCII character, found {ch:?}"))
+        })
+    }
+
+    /// Construct a `Grid<u8>` of digit values (`0..=9`) from a multi-line string
+    ///
+    /// # Panics
+    /// Panics if the string contains a character that is not a decimal digit.
+    pub fn from_str_digits(s: &str) -> Self {
+        Self::parse_with(s, |ch| {
+            ch.to_digit(10)
+                .and_then(|d| u8::try_from(d).ok())
+                .unwrap_or_else(|| panic!("expected a digit, found {ch:?}"))
+        })
+    }
+}
+
+/// Renders one line per row, without separators between cells.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (r, row) in self.rows().enumerate() {
+            if r > 0 {
+                writeln!(f)?;
+            }
+            for value in row {
+                write!(f, "{value}")?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Grid;
+
+    const EXAMPLE: &str = "ab.\n.c#\n#.d\n";
+
+    #[test]
+    fn parses_chars() {
+        let grid = Grid::from_str_chars(EXAMPLE);
+        assert_eq!(grid.width(), 3);
+        assert_eq!(grid.height(), 3);
+        assert_eq!(grid.get(1, 1), Some(&'c'));
+        assert_eq!(grid.get(3, 0), None);
+    }
+
+    #[test]
+    fn parses_bytes_and_digits() {
+        let bytes = Grid::from_str_bytes("12\n34");
+        assert_eq!(bytes.row(1), b"34");
+
+        let digits = Grid::from_str_digits("12\n34");
+        assert_eq!(digits.row(1), &[3, 4]);
+        assert_eq!(digits.column(1).copied().collect::<Vec<_>>(), vec![2, 4]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_ragged_input() {
+        Grid::from_str_chars("abc\nde\n");
+    }
+
+    #[test]
+    fn iterates_neighbors() {
+        let grid = Grid::from_str_chars(EXAMPLE);
+        assert_eq!(
+            grid.neighbors4(0, 0).collect::<Vec<_>>(),
+            vec![(0, 1), (1, 0)]
+        );
+        assert_eq!(grid.neighbors8(0, 0).count(), 3);
+        assert_eq!(grid.neighbors4(1, 1).count(), 4);
+        assert_eq!(grid.neighbors8(1, 1).count(), 8);
+    }
+
+    #[test]
+    fn iterates_coords_row_major() {
+        let grid = Grid::new(2, 2, 0);
+        assert_eq!(
+            grid.coords().collect::<Vec<_>>(),
+            vec![(0, 0), (0, 1), (1, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn transposes_and_rotates() {
+        let grid = Grid::from_str_chars("abc\ndef");
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_cw().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_ccw().to_string(), "cf\nbe\nad");
+        assert_eq!(grid.rotate_cw().rotate_ccw(), grid);
+    }
+
+    #[test]
+    fn flood_fills_regions() {
+        let grid = Grid::from_str_chars("..#\n.##\n#..");
+        let region = grid.flood_fill((0, 0), |ch| *ch == '.');
+        assert_eq!(region, vec![(0, 0), (0, 1), (1, 0)]);
+        assert!(grid.flood_fill((5, 5), |_| true).is_empty());
+    }
+
+    #[test]
+    fn finds_values() {
+        let grid = Grid::from_str_chars(EXAMPLE);
+        assert_eq!(grid.find(&'#'), Some((1, 2)));
+        assert_eq!(grid.find(&'x'), None);
+        assert_eq!(
+            grid.find_all(&'#').collect::<Vec<_>>(),
+            vec![(1, 2), (2, 0)]
+        );
+    }
+
+    #[test]
+    fn displays_rows() {
+        let grid = Grid::from_str_chars(EXAMPLE);
+        assert_eq!(grid.to_string(), "ab.\n.c#\n#.d");
+    }
+}
@@ -0,0 +1,321 @@
+//! Sets of integers stored as sorted, disjoint inclusive intervals.
+use std::collections::BTreeMap;
+use std::fmt::Debug;
+use std::ops::{Add, RangeInclusive, Sub};
+
+/// An integer type that can be used as the bounds of an [`IntervalSet`].
+pub trait Endpoint: Copy + Ord + Debug + Add<Output = Self> + Sub<Output = Self> {
+    const ZERO: Self;
+    const ONE: Self;
+
+    /// The next value, `None` at the maximum.
+    fn successor(self) -> Option<Self>;
+
+    /// The previous value, `None` at the minimum.
+    fn predecessor(self) -> Option<Self>;
+}
+
+macro_rules! impl_endpoint {
+    ($($t:ty),*) => {
+        $(
+            impl Endpoint for $t {
+                const ZERO: Self = 0;
+                const ONE: Self = 1;
+
+                #[inline]
+                fn successor(self) -> Option<Self> {
+                    self.checked_add(1)
+                }
+
+                #[inline]
+                fn predecessor(self) -> Option<Self> {
+                    self.checked_sub(1)
+                }
+            }
+        )*
+    };
+}
+
+impl_endpoint!(
+    u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize
+);
+
+/// A set of integers, stored as inclusive intervals keyed by their start.
+///
+/// Overlapping and adjacent intervals are merged on insertion, so the stored intervals are always disjoint and
+/// sorted. Lookups are `O(log n)` in the number of stored intervals regardless of the insertion order.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct IntervalSet<T> {
+    /// Maps the start of every interval to its (inclusive) end.
+    intervals: BTreeMap<T, T>,
+}
+
+impl<T> Default for IntervalSet<T> {
+    fn default() -> Self {
+        Self {
+            intervals: BTreeMap::new(),
+        }
+    }
+}
+
+impl<T: Endpoint> IntervalSet<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of disjoint intervals, after merging.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.intervals.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.intervals.is_empty()
+    }
+
+    /// Iterate over the disjoint intervals in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = RangeInclusive<T>> + '_ {
+        self.intervals.iter().map(|(&start, &end)| start..=end)
+    }
+
+    /// Adds all values of `range`, merging it with every interval it overlaps or touches. Empty ranges are ignored.
+    pub fn insert(&mut self, range: RangeInclusive<T>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        if let Some((&before_start, &before_end)) = self.intervals.range(..=start).next_back()
+            && (before_end >= start || before_end.successor() == Some(start))
+        {
+            if before_end >= end {
+                return;
+            }
+            start = before_start;
+        }
+
+        // every interval starting inside or right after the new one is swallowed by it.
+        let last = end.successor().unwrap_or(end);
+        let swallowed: Vec<T> = self
+            .intervals
+            .range(start..=last)
+            .map(|(&s, _)| s)
+            .collect();
+        for s in swallowed {
+            end = end.max(self.intervals.remove(&s).unwrap());
+        }
+
+        self.intervals.insert(start, end);
+    }
+
+    /// Removes all values of `range`, splitting the intervals that only partially overlap it.
+    pub fn remove(&mut self, range: RangeInclusive<T>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+
+        let mut overlapping: Vec<(T, T)> = self
+            .intervals
+            .range(start..=end)
+            .map(|(&s, &e)| (s, e))
+            .collect();
+        if let Some((&s, &e)) = self.intervals.range(..start).next_back()
+            && e >= start
+        {
+            overlapping.push((s, e));
+        }
+
+        for (s, e) in overlapping {
+            self.intervals.remove(&s);
+            if s < start {
+                self.intervals.insert(s, start.predecessor().unwrap());
+            }
+            if e > end {
+                self.intervals.insert(end.successor().unwrap(), e);
+            }
+        }
+    }
+
+    /// Adds every interval of `other`.
+    pub fn merge(&mut self, other: &Self) {
+        for range in other.iter() {
+            self.insert(range);
+        }
+    }
+
+    /// Values contained in `self` or `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        let mut union = self.clone();
+        union.merge(other);
+        union
+    }
+
+    /// Values contained in both `self` and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        let mut intersection = Self::new();
+        let mut left = self.iter().peekable();
+        let mut right = other.iter().peekable();
+
+        // both sides are sorted and disjoint, so a single sweep finds every overlap.
+        while let (Some(a), Some(b)) = (left.peek(), right.peek()) {
+            let start = *a.start().max(b.start());
+            let end = *a.end().min(b.end());
+            if start <= end {
+                intersection.intervals.insert(start, end);
+            }
+            if a.end() < b.end() {
+                left.next();
+            } else {
+                right.next();
+            }
+        }
+
+        intersection
+    }
+
+    /// Values contained in `self` but not in `other`.
+    pub fn difference(&self, other: &Self) -> Self {
+        let mut difference = self.clone();
+        for range in other.iter() {
+            difference.remove(range);
+        }
+        difference
+    }
+
+    /// Whether `value` is part of any interval.
+    pub fn contains(&self, value: T) -> bool {
+        self.intervals
+            .range(..=value)
+            .next_back()
+            .is_some_and(|(_, &end)| end >= value)
+    }
+
+    /// Whether every value of `range` is part of the set. Empty ranges are always contained.
+    pub fn contains_range(&self, range: &RangeInclusive<T>) -> bool {
+        range.is_empty()
+            || self
+                .intervals
+                .range(..=*range.start())
+                .next_back()
+                .is_some_and(|(_, end)| end >= range.end())
+    }
+
+    /// Whether any value of `range` is part of the set.
+    pub fn overlaps(&self, range: &RangeInclusive<T>) -> bool {
+        !range.is_empty()
+            && self
+                .intervals
+                .range(..=*range.end())
+                .next_back()
+                .is_some_and(|(_, end)| end >= range.start())
+    }
+
+    /// Number of values in the set.
+    ///
+    /// # Panics
+    /// Panics in debug builds if the count does not fit into `T`.
+    pub fn covered_len(&self) -> T {
+        self.intervals
+            .iter()
+            .map(|(&start, &end)| end - start + T::ONE)
+            .fold(T::ZERO, |sum, len| sum + len)
+    }
+}
+
+impl<T: Endpoint> FromIterator<RangeInclusive<T>> for IntervalSet<T> {
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<T>>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+impl<T: Endpoint> Extend<RangeInclusive<T>> for IntervalSet<T> {
+    fn extend<I: IntoIterator<Item = RangeInclusive<T>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::IntervalSet;
+
+    fn ranges(set: &IntervalSet<i32>) -> Vec<(i32, i32)> {
+        set.iter().map(|r| (*r.start(), *r.end())).collect()
+    }
+
+    #[test]
+    fn merges_overlapping_and_adjacent_intervals() {
+        let set: IntervalSet<i32> = [10..=14, 3..=5, 16..=20, 12..=18, 6..=7, 30..=29]
+            .into_iter()
+            .collect();
+        assert_eq!(ranges(&set), vec![(3, 7), (10, 20)]);
+        assert_eq!(set.len(), 2);
+        assert_eq!(set.covered_len(), 16);
+    }
+
+    #[test]
+    fn inserts_contained_and_enclosing_intervals() {
+        let mut set: IntervalSet<i32> = [2..=4, 8..=9, 12..=12].into_iter().collect();
+        set.insert(3..=3);
+        assert_eq!(ranges(&set), vec![(2, 4), (8, 9), (12, 12)]);
+        set.insert(0..=20);
+        assert_eq!(ranges(&set), vec![(0, 20)]);
+    }
+
+    #[test]
+    fn handles_the_bounds_of_the_type() {
+        let mut set: IntervalSet<u8> = [0..=10, 250..=255, 11..=11].into_iter().collect();
+        assert_eq!(set.len(), 2);
+        assert!(set.contains(255));
+        set.remove(0..=0);
+        set.remove(255..=255);
+        assert_eq!(set.iter().collect::<Vec<_>>(), vec![1..=11, 250..=254]);
+    }
+
+    #[test]
+    fn answers_point_and_range_queries() {
+        let set: IntervalSet<i32> = [-5..=-1, 4..=8].into_iter().collect();
+        assert!(set.contains(-5));
+        assert!(set.contains(8));
+        assert!(!set.contains(0));
+        assert!(!set.contains(9));
+
+        assert!(set.contains_range(&(5..=8)));
+        assert!(!set.contains_range(&(-2..=4)));
+        assert!(set.overlaps(&(-2..=4)));
+        assert!(!set.overlaps(&(0..=3)));
+    }
+
+    #[test]
+    fn removes_and_splits_intervals() {
+        let mut set: IntervalSet<i32> = [0..=10, 20..=30].into_iter().collect();
+        set.remove(3..=5);
+        set.remove(9..=22);
+        assert_eq!(ranges(&set), vec![(0, 2), (6, 8), (23, 30)]);
+    }
+
+    #[test]
+    fn combines_sets() {
+        let a: IntervalSet<i32> = [0..=10, 20..=30].into_iter().collect();
+        let b: IntervalSet<i32> = [5..=25, 40..=41].into_iter().collect();
+
+        assert_eq!(ranges(&a.union(&b)), vec![(0, 30), (40, 41)]);
+        assert_eq!(ranges(&a.intersection(&b)), vec![(5, 10), (20, 25)]);
+        assert_eq!(ranges(&a.difference(&b)), vec![(0, 4), (26, 30)]);
+        assert_eq!(ranges(&b.difference(&a)), vec![(11, 19), (40, 41)]);
+    }
+
+    #[test]
+    fn stays_fast_on_sorted_input() {
+        let set: IntervalSet<u64> = (0..100_000).map(|i| i * 3..=i * 3 + 1).collect();
+        assert_eq!(set.len(), 100_000);
+        assert!((0..100_000).all(|i| set.contains(i * 3 + 1) && !set.contains(i * 3 + 2)));
+    }
+}
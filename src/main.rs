@@ -1,79 +1,225 @@
-use advent_of_code::template::commands::{all, download, read, scaffold, solve, time};
+use advent_of_code::template::commands::{
+    all, batch, dashboard, download, read, scaffold, solve, time, verify, watch,
+};
 use args::{AppArguments, parse};
 
 #[cfg(feature = "today")]
-use advent_of_code::template::Day;
+use advent_of_code::template::PuzzleId;
 #[cfg(feature = "today")]
 use std::process;
 
 mod args {
-    use advent_of_code::template::Day;
+    use advent_of_code::template::commands::time::CompareOptions;
+    use advent_of_code::template::runner::{Format, InputSource, Timeouts};
+    use advent_of_code::template::stats::Statistic;
+    use advent_of_code::template::{Day, PuzzleId, Year};
     use std::process;
+    use std::time::Duration;
 
     pub enum AppArguments {
         Download {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Read {
-            day: Day,
+            puzzle: PuzzleId,
         },
         Scaffold {
-            day: Day,
+            puzzle: PuzzleId,
             download: bool,
             overwrite: bool,
         },
         Solve {
-            day: Day,
+            puzzle: PuzzleId,
             release: bool,
             dhat: bool,
             submit: Option<u8>,
+            format: Format,
+            input: InputSource,
+            inputs_dir: Option<String>,
         },
         All {
+            year: Year,
             release: bool,
+            timeouts: Timeouts,
+            jobs: usize,
         },
         Time {
+            year: Year,
             all: bool,
-            day: Option<Day>,
+            puzzle: Option<PuzzleId>,
             store: bool,
+            stat: Statistic,
+            compare: Option<CompareOptions>,
+            timeouts: Timeouts,
+        },
+        Verify {
+            year: Year,
+            timeouts: Timeouts,
+        },
+        Dashboard {
+            year: Year,
+        },
+        Watch {
+            puzzle: PuzzleId,
+            release: bool,
+            tests: bool,
+            solve: bool,
         },
         #[cfg(feature = "today")]
         Today,
     }
 
+    /// Reads the `--year` option, falling back to the `AOC_YEAR` environment variable.
+    fn parse_year(args: &mut pico_args::Arguments) -> Result<Year, Box<dyn std::error::Error>> {
+        match args.opt_value_from_str("--year")? {
+            Some(year) => Ok(year),
+            None => Year::from_env()
+                .ok_or_else(|| "no year specified. Pass `--year` or set `AOC_YEAR`.".into()),
+        }
+    }
+
+    /// Reads the `--timeout` and `--day-timeout` options, given in seconds.
+    fn parse_timeouts(
+        args: &mut pico_args::Arguments,
+    ) -> Result<Timeouts, Box<dyn std::error::Error>> {
+        let mut seconds =
+            |key: &'static str| -> Result<Option<Duration>, Box<dyn std::error::Error>> {
+                let seconds: Option<f64> = args.opt_value_from_str(key)?;
+                seconds
+                    .map(|seconds| {
+                        Duration::try_from_secs_f64(seconds).map_err(|_| {
+                            format!("`{key}` expects a positive number of seconds.").into()
+                        })
+                    })
+                    .transpose()
+            };
+
+        Ok(Timeouts {
+            part: seconds("--timeout")?,
+            day: seconds("--day-timeout")?,
+        })
+    }
+
+    /// Reads the `--year` option and a free day argument.
+    fn parse_puzzle(
+        args: &mut pico_args::Arguments,
+    ) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        let year = parse_year(args)?;
+        let day: Day = args.free_from_str()?;
+        puzzle_of(year, day)
+    }
+
+    /// Checks that `day` is part of the event of `year`.
+    fn puzzle_of(year: Year, day: Day) -> Result<PuzzleId, Box<dyn std::error::Error>> {
+        PuzzleId::new(year, day).ok_or_else(|| {
+            format!(
+                "the {year} event only lasts until day {}.",
+                year.last_day().into_inner()
+            )
+            .into()
+        })
+    }
+
     pub fn parse() -> Result<AppArguments, Box<dyn std::error::Error>> {
         let mut args = pico_args::Arguments::from_env();
 
         let app_args = match args.subcommand()?.as_deref() {
             Some("all") => AppArguments::All {
+                year: parse_year(&mut args)?,
                 release: args.contains("--release"),
+                timeouts: parse_timeouts(&mut args)?,
+                jobs: args.opt_value_from_str("--jobs")?.unwrap_or(1),
             },
             Some("time") => {
                 let all = args.contains("--all");
                 let store = args.contains("--store");
+                let stat = args.opt_value_from_str("--stat")?.unwrap_or_default();
+                let compare = args.contains("--compare");
+                let timeouts = parse_timeouts(&mut args)?;
+                let baseline = args.opt_value_from_str("--baseline")?;
+                let threshold = args.opt_value_from_str("--threshold")?.unwrap_or(10.0);
+                let year = parse_year(&mut args)?;
+                let day: Option<Day> = args.opt_free_from_str()?;
 
                 AppArguments::Time {
+                    year,
                     all,
-                    day: args.opt_free_from_str()?,
+                    puzzle: day.map(|day| puzzle_of(year, day)).transpose()?,
                     store,
+                    stat,
+                    compare: (compare || baseline.is_some()).then_some(CompareOptions {
+                        baseline,
+                        threshold,
+                    }),
+                    timeouts,
+                }
+            }
+            Some("verify") => AppArguments::Verify {
+                timeouts: parse_timeouts(&mut args)?,
+                year: parse_year(&mut args)?,
+            },
+            Some("dashboard") => AppArguments::Dashboard {
+                year: parse_year(&mut args)?,
+            },
+            Some("watch") => {
+                let release = args.contains("--release");
+                // without either flag, both the tests and the solution are run.
+                let tests = args.contains("--test");
+                let solve = args.contains("--solve");
+                AppArguments::Watch {
+                    puzzle: parse_puzzle(&mut args)?,
+                    release,
+                    tests: tests || !solve,
+                    solve: solve || !tests,
                 }
             }
             Some("download") => AppArguments::Download {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("read") => AppArguments::Read {
-                day: args.free_from_str()?,
+                puzzle: parse_puzzle(&mut args)?,
             },
             Some("scaffold") => AppArguments::Scaffold {
-                day: args.free_from_str()?,
                 download: args.contains("--download"),
                 overwrite: args.contains("--overwrite"),
+                puzzle: parse_puzzle(&mut args)?,
             },
-            Some("solve") => AppArguments::Solve {
-                day: args.free_from_str()?,
-                release: args.contains("--release"),
-                submit: args.opt_value_from_str("--submit")?,
-                dhat: args.contains("--dhat"),
-            },
+            Some("solve") => {
+                let release = args.contains("--release");
+                let submit = args.opt_value_from_str("--submit")?;
+                let dhat = args.contains("--dhat");
+                let format = args.opt_value_from_str("--format")?.unwrap_or_default();
+                let path: Option<String> = args.opt_value_from_str("--input")?;
+                let example = args.contains("--example");
+                let inputs_dir = args.opt_value_from_str("--inputs-dir")?;
+                let puzzle = parse_puzzle(&mut args)?;
+
+                if inputs_dir.is_some() && (path.is_some() || example || submit.is_some()) {
+                    return Err(
+                        "`--inputs-dir` can't be combined with `--input`, `--example` or `--submit`."
+                            .into(),
+                    );
+                }
+
+                let input = match (path, example) {
+                    (Some(_), true) => return Err("pass either `--input` or `--example`.".into()),
+                    (Some(path), false) if path == "-" => InputSource::Stdin,
+                    (Some(path), false) => InputSource::Path(path),
+                    // the example number follows the day, e.g. `cargo solve 5 --example 2`.
+                    (None, true) => InputSource::Example(args.opt_free_from_str()?),
+                    (None, false) => InputSource::Puzzle,
+                };
+
+                AppArguments::Solve {
+                    puzzle,
+                    release,
+                    dhat,
+                    submit,
+                    format,
+                    input,
+                    inputs_dir,
+                }
+            }
             #[cfg(feature = "today")]
             Some("today") => AppArguments::Today,
             Some(x) => {
@@ -102,38 +248,75 @@ fn main() {
             std::process::exit(1);
         }
         Ok(args) => match args {
-            AppArguments::All { release } => all::handle(release),
-            AppArguments::Time { day, all, store } => time::handle(day, all, store),
-            AppArguments::Download { day } => download::handle(day),
-            AppArguments::Read { day } => read::handle(day),
+            AppArguments::All {
+                year,
+                release,
+                timeouts,
+                jobs,
+            } => all::handle(year, release, timeouts, jobs),
+            AppArguments::Time {
+                year,
+                puzzle,
+                all,
+                store,
+                stat,
+                compare,
+                timeouts,
+            } => {
+                if let Err(e) = time::handle(year, puzzle, all, store, stat, compare, timeouts) {
+                    eprintln!("{e}");
+                    std::process::exit(1);
+                }
+            }
+            AppArguments::Verify { year, timeouts } => verify::handle(year, timeouts),
+            AppArguments::Dashboard { year } => dashboard::handle(year),
+            AppArguments::Watch {
+                puzzle,
+                release,
+                tests,
+                solve,
+            } => watch::handle(puzzle, release, tests, solve),
+            AppArguments::Download { puzzle } => download::handle(puzzle),
+            AppArguments::Read { puzzle } => read::handle(puzzle),
             AppArguments::Scaffold {
-                day,
+                puzzle,
                 download,
                 overwrite,
             } => {
-                scaffold::handle(day, overwrite);
+                scaffold::handle(puzzle, overwrite);
                 if download {
-                    download::handle(day);
+                    download::handle(puzzle);
                 }
             }
             AppArguments::Solve {
-                day,
+                puzzle,
                 release,
                 dhat,
                 submit,
-            } => solve::handle(day, release, dhat, submit),
+                format,
+                input,
+                inputs_dir,
+            } => match inputs_dir {
+                Some(dir) => batch::handle(puzzle, release, &dir),
+                None => {
+                    if let Err(e) = solve::handle(puzzle, release, dhat, submit, format, &input) {
+                        eprintln!("Error: {e}");
+                        std::process::exit(1);
+                    }
+                }
+            },
             #[cfg(feature = "today")]
             AppArguments::Today => {
-                match Day::today() {
-                    Some(day) => {
-                        scaffold::handle(day, false);
-                        download::handle(day);
-                        read::handle(day)
+                match PuzzleId::today() {
+                    Some(puzzle) => {
+                        scaffold::handle(puzzle, false);
+                        download::handle(puzzle);
+                        read::handle(puzzle)
                     }
                     None => {
                         eprintln!(
                             "`today` command can only be run between the 1st and \
-                            the 25th of december. Please use `scaffold` with a specific day."
+                            the last day of the event in december. Please use `scaffold` with a specific day."
                         );
                         process::exit(1)
                     }
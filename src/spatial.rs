@@ -0,0 +1,496 @@
+//! Integer points in 2D and 3D, a k-d tree for nearest-neighbour queries and a lazy closest-pairs iterator.
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::error::Error;
+use std::fmt::Display;
+use std::ops::{Add, Sub};
+use std::str::FromStr;
+
+/// A point with integer coordinates along a fixed number of axes.
+pub trait Coordinates: Copy {
+    const DIMENSIONS: usize;
+
+    /// The coordinate along `axis`, which is below [`Coordinates::DIMENSIONS`].
+    fn coordinate(&self, axis: usize) -> i64;
+
+    /// Exact squared euclidean distance to `other`.
+    ///
+    /// # Panics
+    /// Panics in debug builds if the distance does not fit into an `i64`.
+    fn distance_squared(&self, other: &Self) -> i64 {
+        (0..Self::DIMENSIONS)
+            .map(|axis| {
+                let delta = self.coordinate(axis) - other.coordinate(axis);
+                delta * delta
+            })
+            .sum()
+    }
+
+    /// Sum of the absolute coordinate differences to `other`.
+    fn manhattan(&self, other: &Self) -> i64 {
+        (0..Self::DIMENSIONS)
+            .map(|axis| (self.coordinate(axis) - other.coordinate(axis)).abs())
+            .sum()
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+impl Point2 {
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl Point3 {
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl Coordinates for Point2 {
+    const DIMENSIONS: usize = 2;
+
+    #[inline]
+    fn coordinate(&self, axis: usize) -> i64 {
+        match axis {
+            0 => self.x,
+            _ => self.y,
+        }
+    }
+}
+
+impl Coordinates for Point3 {
+    const DIMENSIONS: usize = 3;
+
+    #[inline]
+    fn coordinate(&self, axis: usize) -> i64 {
+        match axis {
+            0 => self.x,
+            1 => self.y,
+            _ => self.z,
+        }
+    }
+}
+
+impl Add for Point2 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl Sub for Point2 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl Add for Point3 {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl Sub for Point3 {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+/// Parses comma separated coordinates, e.g. `3,-4`.
+impl FromStr for Point2 {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y] = parse_coordinates(s)?;
+        Ok(Self::new(x, y))
+    }
+}
+
+/// Parses comma separated coordinates, e.g. `3,-4,5`.
+impl FromStr for Point3 {
+    type Err = ParsePointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let [x, y, z] = parse_coordinates(s)?;
+        Ok(Self::new(x, y, z))
+    }
+}
+
+fn parse_coordinates<const N: usize>(s: &str) -> Result<[i64; N], ParsePointError> {
+    let mut coordinates = [0; N];
+    let mut parts = s.split(',');
+    for coordinate in &mut coordinates {
+        *coordinate = parts
+            .next()
+            .and_then(|part| part.trim().parse().ok())
+            .ok_or(ParsePointError { dimensions: N })?;
+    }
+    match parts.next() {
+        Some(_) => Err(ParsePointError { dimensions: N }),
+        None => Ok(coordinates),
+    }
+}
+
+/// An error which can be returned when parsing a [`Point2`] or [`Point3`].
+#[derive(Debug)]
+pub struct ParsePointError {
+    dimensions: usize,
+}
+
+impl Error for ParsePointError {}
+
+impl Display for ParsePointError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "expecting {} comma separated integer coordinates",
+            self.dimensions
+        )
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A balanced k-d tree over a fixed set of points.
+///
+/// Queries return the indices of the points in the slice the tree was built from, together with their squared
+/// distance to the target. Ties are broken by index, so results are deterministic.
+#[derive(Clone, Debug)]
+pub struct KdTree<P> {
+    /// Points with their original index, laid out so the median of every subslice splits it along its axis.
+    nodes: Vec<(P, usize)>,
+}
+
+impl<P: Coordinates> KdTree<P> {
+    pub fn new(points: &[P]) -> Self {
+        let mut nodes: Vec<(P, usize)> = points.iter().copied().zip(0..).collect();
+        Self::build(&mut nodes, 0);
+        Self { nodes }
+    }
+
+    fn build(nodes: &mut [(P, usize)], depth: usize) {
+        if nodes.len() <= 1 {
+            return;
+        }
+        let axis = depth % P::DIMENSIONS;
+        let mid = nodes.len() / 2;
+        nodes.select_nth_unstable_by_key(mid, |(point, _)| point.coordinate(axis));
+        let (left, right) = nodes.split_at_mut(mid);
+        Self::build(left, depth + 1);
+        Self::build(&mut right[1..], depth + 1);
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// The `k` points closest to `target` as `(index, squared distance)`, closest first.
+    pub fn nearest(&self, target: &P, k: usize) -> Vec<(usize, i64)> {
+        let mut best = BinaryHeap::with_capacity(k + 1);
+        if k > 0 {
+            Self::search_nearest(&self.nodes, 0, target, k, &mut best);
+        }
+        best.into_sorted_vec()
+            .into_iter()
+            .map(|(distance, index)| (index, distance))
+            .collect()
+    }
+
+    /// Keeps the `k` smallest `(squared distance, index)` pairs in the max-heap `best`.
+    fn search_nearest(
+        nodes: &[(P, usize)],
+        depth: usize,
+        target: &P,
+        k: usize,
+        best: &mut BinaryHeap<(i64, usize)>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+
+        let mid = nodes.len() / 2;
+        let (point, index) = nodes[mid];
+        let candidate = (point.distance_squared(target), index);
+        if best.len() < k {
+            best.push(candidate);
+        } else if best.peek().is_some_and(|worst| candidate < *worst) {
+            best.pop();
+            best.push(candidate);
+        }
+
+        let axis = depth % P::DIMENSIONS;
+        let delta = target.coordinate(axis) - point.coordinate(axis);
+        let (near, far) = if delta < 0 {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+
+        Self::search_nearest(near, depth + 1, target, k, best);
+        // ties with the current worst can still win by index, so only strictly farther halves are skipped.
+        if best.len() < k
+            || best
+                .peek()
+                .is_some_and(|&(worst, _)| delta * delta <= worst)
+        {
+            Self::search_nearest(far, depth + 1, target, k, best);
+        }
+    }
+
+    /// Every point at most `radius` away from `target` as `(index, squared distance)`, closest first.
+    pub fn within(&self, target: &P, radius: i64) -> Vec<(usize, i64)> {
+        let mut found = vec![];
+        Self::search_within(&self.nodes, 0, target, radius * radius, &mut found);
+        found.sort_unstable_by_key(|&(index, distance)| (distance, index));
+        found
+    }
+
+    fn search_within(
+        nodes: &[(P, usize)],
+        depth: usize,
+        target: &P,
+        radius_squared: i64,
+        found: &mut Vec<(usize, i64)>,
+    ) {
+        if nodes.is_empty() {
+            return;
+        }
+
+        let mid = nodes.len() / 2;
+        let (point, index) = nodes[mid];
+        let distance = point.distance_squared(target);
+        if distance <= radius_squared {
+            found.push((index, distance));
+        }
+
+        let axis = depth % P::DIMENSIONS;
+        let delta = target.coordinate(axis) - point.coordinate(axis);
+        let (near, far) = if delta < 0 {
+            (&nodes[..mid], &nodes[mid + 1..])
+        } else {
+            (&nodes[mid + 1..], &nodes[..mid])
+        };
+
+        Self::search_within(near, depth + 1, target, radius_squared, found);
+        if delta * delta <= radius_squared {
+            Self::search_within(far, depth + 1, target, radius_squared, found);
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Number of neighbours fetched for a point the first time, doubled whenever they run out.
+const INITIAL_NEIGHBOURS: usize = 4;
+
+/// Iterates over all pairs of `points` as `(i, j, squared distance)` with `i < j`, ordered by distance, then `i`, then
+/// `j`.
+///
+/// Pairs are produced lazily from per-point nearest neighbour queries, so taking the closest `k` pairs needs far
+/// less than the `O(n²)` memory of sorting all pairs.
+pub fn closest_pairs<P: Coordinates>(points: &[P]) -> ClosestPairs<P> {
+    let mut pairs = ClosestPairs {
+        tree: KdTree::new(points),
+        points: points.to_vec(),
+        neighbours: vec![vec![]; points.len()],
+        queue: BinaryHeap::new(),
+    };
+    for index in 0..points.len() {
+        pairs.queue_next(index, 0);
+    }
+    pairs
+}
+
+/// Iterator returned by [`closest_pairs`].
+pub struct ClosestPairs<P> {
+    tree: KdTree<P>,
+    points: Vec<P>,
+    /// The nearest neighbours of every point fetched so far, closest first.
+    neighbours: Vec<Vec<(usize, i64)>>,
+    /// The next unseen neighbour of every point as `(squared distance, point, neighbour, rank)`.
+    queue: BinaryHeap<Reverse<(i64, usize, usize, usize)>>,
+}
+
+impl<P: Coordinates> ClosestPairs<P> {
+    /// Queues the neighbour of `index` with `rank`, fetching more neighbours if needed.
+    fn queue_next(&mut self, index: usize, rank: usize) {
+        let others = self.points.len() - 1;
+        if rank >= others {
+            return;
+        }
+
+        if rank >= self.neighbours[index].len() {
+            let k = (2 * rank).max(INITIAL_NEIGHBOURS).min(others);
+            // the point itself is among its nearest neighbours, so one more is fetched and it is dropped again.
+            self.neighbours[index] = self
+                .tree
+                .nearest(&self.points[index], k + 1)
+                .into_iter()
+                .filter(|&(neighbour, _)| neighbour != index)
+                .take(k)
+                .collect();
+        }
+
+        let (neighbour, distance) = self.neighbours[index][rank];
+        self.queue.push(Reverse((distance, index, neighbour, rank)));
+    }
+}
+
+impl<P: Coordinates> Iterator for ClosestPairs<P> {
+    type Item = (usize, usize, i64);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // every pair shows up once from each of its points, only the one from the lower index is yielded.
+        while let Some(Reverse((distance, index, neighbour, rank))) = self.queue.pop() {
+            self.queue_next(index, rank + 1);
+            if index < neighbour {
+                return Some((index, neighbour, distance));
+            }
+        }
+        None
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Coordinates, KdTree, Point2, Point3, closest_pairs};
+
+    /// Deterministic pseudo-random points, with duplicates and ties.
+    fn points(count: usize) -> Vec<Point3> {
+        let mut state: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = || {
+            state = state
+                .wrapping_mul(6_364_136_223_846_793_005)
+                .wrapping_add(1);
+            i64::try_from(state >> 58).unwrap() - 32
+        };
+        (0..count)
+            .map(|_| Point3::new(next(), next(), next()))
+            .collect()
+    }
+
+    fn brute_force_nearest(points: &[Point3], target: &Point3, k: usize) -> Vec<(usize, i64)> {
+        let mut all: Vec<(usize, i64)> = points
+            .iter()
+            .enumerate()
+            .map(|(index, point)| (index, point.distance_squared(target)))
+            .collect();
+        all.sort_unstable_by_key(|&(index, distance)| (distance, index));
+        all.truncate(k);
+        all
+    }
+
+    #[test]
+    fn computes_distances() {
+        let a = Point3::new(1, 2, 3);
+        let b = Point3::new(4, -2, 3);
+        assert_eq!(a.distance_squared(&b), 25);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(b - a, Point3::new(3, -4, 0));
+        assert_eq!(Point2::new(1, 1) + Point2::new(2, 3), Point2::new(3, 4));
+    }
+
+    #[test]
+    fn parses_points() {
+        assert_eq!("3,-4".parse::<Point2>().unwrap(), Point2::new(3, -4));
+        assert_eq!(
+            "162,817,812".parse::<Point3>().unwrap(),
+            Point3::new(162, 817, 812)
+        );
+        assert!("1,2".parse::<Point3>().is_err());
+        assert!("1,2,3".parse::<Point2>().is_err());
+        assert!("1,a".parse::<Point2>().is_err());
+    }
+
+    #[test]
+    fn finds_nearest_neighbours() {
+        let points = points(500);
+        let tree = KdTree::new(&points);
+        assert_eq!(tree.len(), 500);
+
+        for target in points
+            .iter()
+            .step_by(25)
+            .chain(&[Point3::new(100, 0, -100)])
+        {
+            for k in [0, 1, 7, 40, 600] {
+                assert_eq!(
+                    tree.nearest(target, k),
+                    brute_force_nearest(&points, target, k)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn finds_points_within_radius() {
+        let points = points(500);
+        let tree = KdTree::new(&points);
+
+        for radius in [0, 5, 20] {
+            let target = points[3];
+            let expected: Vec<(usize, i64)> = brute_force_nearest(&points, &target, points.len())
+                .into_iter()
+                .filter(|&(_, distance)| distance <= radius * radius)
+                .collect();
+            assert_eq!(tree.within(&target, radius), expected);
+        }
+    }
+
+    #[test]
+    fn yields_closest_pairs_in_order() {
+        let points = points(200);
+        let mut expected = vec![];
+        for i in 0..points.len() {
+            for j in i + 1..points.len() {
+                expected.push((i, j, points[i].distance_squared(&points[j])));
+            }
+        }
+        expected.sort_unstable_by_key(|&(i, j, distance)| (distance, i, j));
+
+        assert_eq!(
+            closest_pairs(&points).take(50).collect::<Vec<_>>(),
+            expected[..50]
+        );
+        assert_eq!(closest_pairs(&points).collect::<Vec<_>>(), expected);
+    }
+
+    #[test]
+    fn handles_tiny_inputs() {
+        assert_eq!(closest_pairs::<Point2>(&[]).next(), None);
+        assert_eq!(closest_pairs(&[Point2::new(1, 1)]).next(), None);
+        assert!(
+            KdTree::<Point2>::new(&[])
+                .nearest(&Point2::new(0, 0), 3)
+                .is_empty()
+        );
+    }
+}
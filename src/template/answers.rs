@@ -0,0 +1,459 @@
+use std::{
+    collections::HashMap,
+    fmt::Display,
+    fs,
+    io::{Error, ErrorKind},
+    str::FromStr,
+};
+use tinyjson::JsonValue;
+
+use crate::template::{Day, PuzzleId, Year, aoc_client::AocClientError};
+
+static ANSWERS_FILE_PATH: &str = "./data/answers.json";
+
+/// The response of adventofcode.com to a submitted answer.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Correct,
+    Wrong,
+    TooHigh,
+    TooLow,
+    RateLimited,
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Maps the result of a submission to a verdict. Returns `None` if the answer did not reach the site.
+    pub fn from_submission(result: &Result<(), AocClientError>) -> Option<Self> {
+        match result {
+            Ok(()) => Some(Verdict::Correct),
+            Err(AocClientError::WrongAnswer) => Some(Verdict::Wrong),
+            Err(AocClientError::TooHigh) => Some(Verdict::TooHigh),
+            Err(AocClientError::TooLow) => Some(Verdict::TooLow),
+            Err(AocClientError::RateLimited(_)) => Some(Verdict::RateLimited),
+            Err(AocClientError::AlreadySolved) => Some(Verdict::AlreadySolved),
+            Err(_) => None,
+        }
+    }
+
+    fn as_str(self) -> &'static str {
+        match self {
+            Verdict::Correct => "correct",
+            Verdict::Wrong => "wrong",
+            Verdict::TooHigh => "too_high",
+            Verdict::TooLow => "too_low",
+            Verdict::RateLimited => "rate_limited",
+            Verdict::AlreadySolved => "already_solved",
+        }
+    }
+
+    /// Returns `true` if the site rejected the answer itself.
+    fn is_wrong(self) -> bool {
+        matches!(self, Verdict::Wrong | Verdict::TooHigh | Verdict::TooLow)
+    }
+}
+
+impl FromStr for Verdict {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "correct" => Ok(Verdict::Correct),
+            "wrong" => Ok(Verdict::Wrong),
+            "too_high" => Ok(Verdict::TooHigh),
+            "too_low" => Ok(Verdict::TooLow),
+            "rate_limited" => Ok(Verdict::RateLimited),
+            "already_solved" => Ok(Verdict::AlreadySolved),
+            _ => Err(format!("Unknown verdict `{s}`.")),
+        }
+    }
+}
+
+/// A single answer that was sent to the site.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Submission {
+    pub answer: String,
+    pub verdict: Verdict,
+}
+
+/// Represents the submission history of one part of a day.
+#[derive(Clone, Debug)]
+pub struct PartAnswers {
+    pub year: Year,
+    pub day: Day,
+    pub part: u8,
+    pub correct: Option<String>,
+    pub submissions: Vec<Submission>,
+}
+
+impl PartAnswers {
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::new_unchecked(self.year, self.day)
+    }
+
+    /// The lowest answer that was too high and the highest answer that was too low.
+    fn bounds(&self) -> (Option<i128>, Option<i128>) {
+        let numeric = |verdict: Verdict| {
+            self.submissions
+                .iter()
+                .filter(move |s| s.verdict == verdict)
+                .filter_map(|s| s.answer.parse::<i128>().ok())
+        };
+        (
+            numeric(Verdict::TooHigh).min(),
+            numeric(Verdict::TooLow).max(),
+        )
+    }
+}
+
+/// Reasons to not send an answer to the site.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Refusal {
+    /// The part is solved, holds the accepted answer.
+    AlreadyCorrect(String),
+    /// The answer was rejected before.
+    KnownWrong,
+    /// The answer is not below an answer that was too high.
+    TooHigh(i128),
+    /// The answer is not above an answer that was too low.
+    TooLow(i128),
+}
+
+impl Display for Refusal {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => {
+                write!(
+                    f,
+                    "this part is already solved, the accepted answer is {answer}."
+                )
+            }
+            Refusal::KnownWrong => write!(f, "this answer was already rejected."),
+            Refusal::TooHigh(bound) => {
+                write!(f, "this answer is too high, {bound} was already too high.")
+            }
+            Refusal::TooLow(bound) => {
+                write!(f, "this answer is too low, {bound} was already too low.")
+            }
+        }
+    }
+}
+
+/// Represents the submitted answers of all days.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct Answers {
+    pub data: Vec<PartAnswers>,
+}
+
+impl Answers {
+    /// Dehydrate answers to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self.clone());
+        let mut file = fs::File::create(ANSWERS_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate answers from a JSON file. If not present, returns an empty ledger.
+    ///
+    /// A file that can't be read or parsed is an error, so it is never overwritten by a ledger missing its answers.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(ANSWERS_FILE_PATH) {
+            Ok(content) => Answers::try_from(content),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    pub fn get(&self, puzzle: PuzzleId, part: u8) -> Option<&PartAnswers> {
+        self.data
+            .iter()
+            .find(|a| a.puzzle() == puzzle && a.part == part)
+    }
+
+    /// The accepted answer of a part, if it was solved.
+    pub fn correct(&self, puzzle: PuzzleId, part: u8) -> Option<&str> {
+        self.get(puzzle, part)?.correct.as_deref()
+    }
+
+    /// Checks whether `answer` is worth sending to the site.
+    pub fn check(&self, puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), Refusal> {
+        let Some(answers) = self.get(puzzle, part) else {
+            return Ok(());
+        };
+
+        if let Some(correct) = &answers.correct {
+            return Err(Refusal::AlreadyCorrect(correct.clone()));
+        }
+
+        if answers
+            .submissions
+            .iter()
+            .any(|s| s.answer == answer && s.verdict.is_wrong())
+        {
+            return Err(Refusal::KnownWrong);
+        }
+
+        if let Ok(value) = answer.parse::<i128>() {
+            let (high, low) = answers.bounds();
+            if let Some(high) = high.filter(|high| value >= *high) {
+                return Err(Refusal::TooHigh(high));
+            }
+            if let Some(low) = low.filter(|low| value <= *low) {
+                return Err(Refusal::TooLow(low));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Records a submission and its verdict, remembering the answer if it is correct.
+    pub fn record(&mut self, puzzle: PuzzleId, part: u8, answer: &str, verdict: Verdict) {
+        if self.get(puzzle, part).is_none() {
+            self.data.push(PartAnswers {
+                year: puzzle.year(),
+                day: puzzle.day(),
+                part,
+                correct: None,
+                submissions: vec![],
+            });
+            self.data.sort_unstable_by_key(|a| (a.puzzle(), a.part));
+        }
+
+        let answers = self
+            .data
+            .iter_mut()
+            .find(|a| a.puzzle() == puzzle && a.part == part)
+            .unwrap();
+        answers.submissions.push(Submission {
+            answer: answer.into(),
+            verdict,
+        });
+        if verdict == Verdict::Correct {
+            answers.correct = Some(answer.into());
+        }
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<Answers> for JsonValue {
+    fn from(value: Answers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "data".into(),
+            JsonValue::Array(value.data.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for Answers {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_data = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("data")
+            .ok_or("expected JSON document to have key `data`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.data` to be an array.")?;
+
+        Ok(Answers {
+            data: json_data
+                .iter()
+                .map(PartAnswers::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartAnswers> for JsonValue {
+    fn from(value: &PartAnswers) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
+        map.insert("day".into(), JsonValue::String(value.day.to_string()));
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "correct".into(),
+            match &value.correct {
+                Some(x) => JsonValue::String(x.clone()),
+                None => JsonValue::Null,
+            },
+        );
+        map.insert(
+            "submissions".into(),
+            JsonValue::Array(
+                value
+                    .submissions
+                    .iter()
+                    .map(|s| {
+                        let mut submission: HashMap<String, JsonValue> = HashMap::new();
+                        submission.insert("answer".into(), JsonValue::String(s.answer.clone()));
+                        submission.insert(
+                            "verdict".into(),
+                            JsonValue::String(s.verdict.as_str().into()),
+                        );
+                        JsonValue::Object(submission)
+                    })
+                    .collect(),
+            ),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartAnswers {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected answers to be a JSON object.")?;
+
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected answers.year to be a Year struct.")?;
+
+        let day = json
+            .get("day")
+            .and_then(|v| v.get::<String>())
+            .and_then(|day| Day::from_str(day).ok())
+            .filter(|day| year.contains(*day))
+            .ok_or("Expected answers.day to be a Day struct of answers.year.")?;
+
+        let part = json
+            .get("part")
+            .and_then(|v| v.get::<f64>().copied())
+            .filter(|part| *part == 1.0 || *part == 2.0)
+            .ok_or("Expected answers.part to be 1 or 2.")?;
+
+        let correct = json
+            .get("correct")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected answers.correct to be null or string.")?;
+
+        let submissions = json
+            .get("submissions")
+            .and_then(|v| v.get::<Vec<JsonValue>>())
+            .ok_or("Expected answers.submissions to be an array.")?
+            .iter()
+            .map(|s| {
+                let s = s
+                    .get::<HashMap<String, JsonValue>>()
+                    .ok_or("Expected submission to be a JSON object.")?;
+                let answer = s
+                    .get("answer")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected submission.answer to be a string.")?;
+                let verdict = s
+                    .get("verdict")
+                    .and_then(|v| v.get::<String>())
+                    .ok_or("Expected submission.verdict to be a string.")?
+                    .parse()?;
+                Ok(Submission {
+                    answer: answer.clone(),
+                    verdict,
+                })
+            })
+            .collect::<Result<_, String>>()?;
+
+        Ok(PartAnswers {
+            year,
+            day,
+            #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+            part: part as u8,
+            correct: correct.cloned(),
+            submissions,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Answers, Refusal, Verdict};
+    use crate::{day, template::PuzzleId, year};
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(year!(2025), day!(1)).unwrap()
+    }
+
+    #[test]
+    fn allows_unknown_answers() {
+        let answers = Answers::default();
+        assert_eq!(answers.check(puzzle(), 1, "42"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let mut answers = Answers::default();
+        answers.record(puzzle(), 1, "abc", Verdict::Wrong);
+        answers.record(puzzle(), 1, "def", Verdict::RateLimited);
+
+        assert_eq!(answers.check(puzzle(), 1, "abc"), Err(Refusal::KnownWrong));
+        assert_eq!(answers.check(puzzle(), 1, "def"), Ok(()));
+        assert_eq!(answers.check(puzzle(), 2, "abc"), Ok(()));
+    }
+
+    #[test]
+    fn refuses_answers_outside_of_bounds() {
+        let mut answers = Answers::default();
+        answers.record(puzzle(), 1, "100", Verdict::TooHigh);
+        answers.record(puzzle(), 1, "80", Verdict::TooHigh);
+        answers.record(puzzle(), 1, "10", Verdict::TooLow);
+
+        assert_eq!(answers.check(puzzle(), 1, "80"), Err(Refusal::KnownWrong));
+        assert_eq!(answers.check(puzzle(), 1, "90"), Err(Refusal::TooHigh(80)));
+        assert_eq!(answers.check(puzzle(), 1, "5"), Err(Refusal::TooLow(10)));
+        assert_eq!(answers.check(puzzle(), 1, "50"), Ok(()));
+    }
+
+    #[test]
+    fn stores_correct_answers() {
+        let mut answers = Answers::default();
+        answers.record(puzzle(), 2, "7", Verdict::TooLow);
+        answers.record(puzzle(), 2, "8", Verdict::Correct);
+
+        assert_eq!(answers.correct(puzzle(), 2), Some("8"));
+        assert_eq!(answers.correct(puzzle(), 1), None);
+        assert_eq!(
+            answers.check(puzzle(), 2, "9"),
+            Err(Refusal::AlreadyCorrect("8".into()))
+        );
+    }
+
+    #[test]
+    fn roundtrips_json() {
+        let mut answers = Answers::default();
+        answers.record(puzzle(), 1, "100", Verdict::TooHigh);
+        answers.record(puzzle(), 1, "50", Verdict::Correct);
+
+        let json = tinyjson::JsonValue::from(answers).stringify().unwrap();
+        let answers = Answers::try_from(json).unwrap();
+
+        assert_eq!(answers.data.len(), 1);
+        assert_eq!(answers.data[0].submissions.len(), 2);
+        assert_eq!(answers.data[0].submissions[0].verdict, Verdict::TooHigh);
+        assert_eq!(answers.correct(puzzle(), 1), Some("50"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_for_malformed_answers() {
+        let json = r#"{ "data": [{ "year": "2025", "day": "01", "part": 3 }] }"#.to_string();
+        Answers::try_from(json).unwrap();
+    }
+}
@@ -0,0 +1,440 @@
+/// HTTP client for adventofcode.com: downloads inputs and puzzle descriptions and submits answers.
+use std::{env, fmt::Display, fs, io, path::Path, path::PathBuf, time::Duration};
+
+use ureq::Agent;
+
+use crate::template::{PuzzleId, markdown};
+
+static BASE_URL: &str = "https://adventofcode.com";
+static USER_AGENT: &str = "github.com/DirajHS/advent-of-code-2025";
+
+/// Environment variable holding the session cookie.
+static SESSION_ENV: &str = "AOC_SESSION";
+/// Environment variable pointing to a file that holds the session cookie.
+static SESSION_FILE_ENV: &str = "AOC_SESSION_FILE";
+/// Default session cookie file in the home folder, shared with aoc-cli.
+static SESSION_FILE_NAME: &str = ".adventofcode.session";
+
+#[derive(Debug)]
+pub enum AocClientError {
+    /// No session cookie was found in the environment or the session file.
+    NoSession,
+    /// The session cookie was rejected, it probably expired.
+    NotLoggedIn,
+    /// The answer is wrong.
+    WrongAnswer,
+    /// The answer is wrong and too high.
+    TooHigh,
+    /// The answer is wrong and too low.
+    TooLow,
+    /// An answer was submitted too recently, the next one can be sent after the wait time.
+    RateLimited(Duration),
+    /// The part was already solved or is not unlocked yet.
+    AlreadySolved,
+    /// The submission response could not be understood, holds its text.
+    UnknownResponse(String),
+    /// The server responded with an unexpected status code.
+    BadStatus(u16),
+    Http(ureq::Error),
+    IO(io::Error),
+}
+
+impl Display for AocClientError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AocClientError::NoSession => write!(
+                f,
+                "no session cookie found. Set `{SESSION_ENV}` or store it in `~/{SESSION_FILE_NAME}`."
+            ),
+            AocClientError::NotLoggedIn => {
+                write!(
+                    f,
+                    "not logged in, the session cookie is invalid or expired."
+                )
+            }
+            AocClientError::WrongAnswer => write!(f, "that's not the right answer."),
+            AocClientError::TooHigh => write!(f, "that's not the right answer, it is too high."),
+            AocClientError::TooLow => write!(f, "that's not the right answer, it is too low."),
+            AocClientError::RateLimited(wait) => write!(
+                f,
+                "an answer was submitted too recently, wait {}s before trying again.",
+                wait.as_secs()
+            ),
+            AocClientError::AlreadySolved => {
+                write!(f, "this part is already solved or not unlocked yet.")
+            }
+            AocClientError::UnknownResponse(text) => write!(f, "unexpected response: {text}"),
+            AocClientError::BadStatus(status) => {
+                write!(f, "server responded with status {status}.")
+            }
+            AocClientError::Http(e) => write!(f, "request failed: {e}"),
+            AocClientError::IO(e) => write!(f, "could not write file: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for AocClientError {}
+
+impl From<ureq::Error> for AocClientError {
+    fn from(e: ureq::Error) -> Self {
+        AocClientError::Http(e)
+    }
+}
+
+impl From<io::Error> for AocClientError {
+    fn from(e: io::Error) -> Self {
+        AocClientError::IO(e)
+    }
+}
+
+/// Reads the session cookie from `AOC_SESSION`, or else from the file at `AOC_SESSION_FILE`
+/// or `~/.adventofcode.session`.
+pub fn read_session() -> Result<String, AocClientError> {
+    if let Ok(session) = env::var(SESSION_ENV)
+        && !session.trim().is_empty()
+    {
+        return Ok(session.trim().to_string());
+    }
+
+    let path = env::var_os(SESSION_FILE_ENV)
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(SESSION_FILE_NAME)))
+        .ok_or(AocClientError::NoSession)?;
+
+    fs::read_to_string(path)
+        .ok()
+        .map(|session| session.trim().to_string())
+        .filter(|session| !session.is_empty())
+        .ok_or(AocClientError::NoSession)
+}
+
+pub struct AocClient {
+    agent: Agent,
+    base_url: String,
+    session: String,
+}
+
+impl AocClient {
+    pub fn new(session: String) -> Self {
+        let agent = Agent::config_builder()
+            .http_status_as_error(false)
+            // a missing or expired session redirects submissions to the puzzle page.
+            .max_redirects(0)
+            .user_agent(USER_AGENT)
+            .build()
+            .into();
+
+        Self {
+            agent,
+            base_url: BASE_URL.into(),
+            session,
+        }
+    }
+
+    /// Creates a client with the session cookie from [`read_session`].
+    pub fn from_env() -> Result<Self, AocClientError> {
+        read_session().map(Self::new)
+    }
+
+    /// Points the client to another server, e.g. a local mock.
+    #[must_use]
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').into();
+        self
+    }
+
+    fn day_url(&self, puzzle: PuzzleId) -> String {
+        format!(
+            "{}/{}/day/{}",
+            self.base_url,
+            puzzle.year(),
+            puzzle.day().into_inner()
+        )
+    }
+
+    fn cookie(&self) -> String {
+        format!("session={}", self.session)
+    }
+
+    fn get(&self, url: &str) -> Result<String, AocClientError> {
+        let mut response = self.agent.get(url).header("Cookie", self.cookie()).call()?;
+        match response.status().as_u16() {
+            200 => Ok(response.body_mut().read_to_string()?),
+            // inputs respond with 400 and other pages redirect without a valid session.
+            300..=400 => Err(AocClientError::NotLoggedIn),
+            status => Err(AocClientError::BadStatus(status)),
+        }
+    }
+
+    /// Fetches the puzzle input.
+    pub fn input(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        self.get(&format!("{}/input", self.day_url(puzzle)))
+    }
+
+    /// Fetches the puzzle description as Markdown. Includes part two once part one is solved.
+    pub fn puzzle(&self, puzzle: PuzzleId) -> Result<String, AocClientError> {
+        let html = self.get(&self.day_url(puzzle))?;
+        Ok(markdown::puzzle_to_markdown(&html))
+    }
+
+    /// Submits an answer. Returns `Ok` if the answer is correct.
+    pub fn submit(&self, puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), AocClientError> {
+        let mut response = self
+            .agent
+            .post(format!("{}/answer", self.day_url(puzzle)))
+            .header("Cookie", self.cookie())
+            .send_form([("level", part.to_string().as_str()), ("answer", answer)])?;
+
+        match response.status().as_u16() {
+            200 => parse_submission(&response.body_mut().read_to_string()?),
+            300..=400 => Err(AocClientError::NotLoggedIn),
+            status => Err(AocClientError::BadStatus(status)),
+        }
+    }
+}
+
+/// Interprets the `<article>` of a submission response page.
+fn parse_submission(html: &str) -> Result<(), AocClientError> {
+    let text = html
+        .split_once("<article>")
+        .and_then(|(_, rest)| rest.split_once("</article>"))
+        .map_or(html, |(article, _)| article);
+    let text = markdown::to_markdown(text);
+
+    if text.contains("That's the right answer") {
+        Ok(())
+    } else if text.contains("That's not the right answer") {
+        if text.contains("too high") {
+            Err(AocClientError::TooHigh)
+        } else if text.contains("too low") {
+            Err(AocClientError::TooLow)
+        } else {
+            Err(AocClientError::WrongAnswer)
+        }
+    } else if text.contains("You gave an answer too recently") {
+        Err(AocClientError::RateLimited(parse_wait_time(&text)))
+    } else if text.contains("You don't seem to be solving the right level") {
+        Err(AocClientError::AlreadySolved)
+    } else {
+        Err(AocClientError::UnknownResponse(text))
+    }
+}
+
+/// Parses "You have 1m 5s left to wait." into a duration.
+fn parse_wait_time(text: &str) -> Duration {
+    let secs = text
+        .split_once("You have ")
+        .and_then(|(_, rest)| rest.split_once(" left to wait"))
+        .map_or(0, |(wait, _)| {
+            wait.split_whitespace()
+                .filter_map(|part| match part.split_at(part.len().saturating_sub(1)) {
+                    (value, "m") => value.parse::<u64>().ok().map(|m| m * 60),
+                    (value, "s") => value.parse::<u64>().ok(),
+                    _ => None,
+                })
+                .sum()
+        });
+    Duration::from_secs(secs)
+}
+
+fn write_file(path: &str, contents: &str) -> Result<(), AocClientError> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, contents)?;
+    Ok(())
+}
+
+/// Downloads the input and the puzzle description into the `data` folder.
+pub fn download(puzzle: PuzzleId) -> Result<(), AocClientError> {
+    let client = AocClient::from_env()?;
+    let input_path = puzzle.data_path("inputs", "txt");
+    let puzzle_path = puzzle.data_path("puzzles", "md");
+
+    write_file(&input_path, &client.input(puzzle)?)?;
+    write_file(&puzzle_path, &client.puzzle(puzzle)?)?;
+
+    println!("🎄 Successfully wrote input to \"{}\".", &input_path);
+    println!("🎄 Successfully wrote puzzle to \"{}\".", &puzzle_path);
+    Ok(())
+}
+
+/// Downloads the puzzle description into the `data` folder and returns it.
+pub fn read(puzzle: PuzzleId) -> Result<String, AocClientError> {
+    let client = AocClient::from_env()?;
+    let description = client.puzzle(puzzle)?;
+    write_file(&puzzle.data_path("puzzles", "md"), &description)?;
+    Ok(description)
+}
+
+pub fn submit(puzzle: PuzzleId, part: u8, answer: &str) -> Result<(), AocClientError> {
+    AocClient::from_env()?.submit(puzzle, part, answer)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{AocClient, AocClientError};
+    use crate::{day, template::PuzzleId, year};
+    use std::{
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        thread,
+        time::Duration,
+    };
+
+    /// Serves one canned `(status, body)` response per connection and returns the raw requests.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, thread::JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream);
+
+                let mut request = String::new();
+                let mut content_length = 0;
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if let Some(len) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                        content_length = len.trim().parse().unwrap();
+                    }
+                    request.push_str(&line);
+                    if line == "\r\n" {
+                        break;
+                    }
+                }
+                let mut content = vec![0; content_length];
+                reader.read_exact(&mut content).unwrap();
+                request.push_str(&String::from_utf8(content).unwrap());
+                requests.push(request);
+
+                write!(
+                    reader.get_mut(),
+                    "HTTP/1.1 {status} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+
+        (url, handle)
+    }
+
+    fn client(url: &str) -> AocClient {
+        AocClient::new("secret".into()).with_base_url(url)
+    }
+
+    fn puzzle() -> PuzzleId {
+        PuzzleId::new(year!(2025), day!(5)).unwrap()
+    }
+
+    #[test]
+    fn fetches_input_with_session() {
+        let (url, server) = serve(vec![(200, "1-3\n5\n")]);
+        assert_eq!(client(&url).input(puzzle()).unwrap(), "1-3\n5\n");
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("GET /2025/day/5/input "));
+        assert!(requests[0].contains("session=secret"));
+    }
+
+    #[test]
+    fn fetches_puzzle_as_markdown() {
+        let (url, server) = serve(vec![(
+            200,
+            "<main><article class=\"day-desc\"><h2>--- Day 5 ---</h2><p>Hi <em>there</em>.</p></article></main>",
+        )]);
+        assert_eq!(
+            client(&url).puzzle(puzzle()).unwrap(),
+            "## --- Day 5 ---\n\nHi *there*."
+        );
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn reports_invalid_sessions() {
+        let (url, server) = serve(vec![(400, "Please log in."), (302, "")]);
+        assert!(matches!(
+            client(&url).input(puzzle()),
+            Err(AocClientError::NotLoggedIn)
+        ));
+        assert!(matches!(
+            client(&url).submit(puzzle(), 1, "3"),
+            Err(AocClientError::NotLoggedIn)
+        ));
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn submits_answers() {
+        let (url, server) = serve(vec![(
+            200,
+            "<article><p>That's the right answer! You are one gold star closer.</p></article>",
+        )]);
+        assert!(client(&url).submit(puzzle(), 2, "14").is_ok());
+
+        let requests = server.join().unwrap();
+        assert!(requests[0].starts_with("POST /2025/day/5/answer "));
+        assert!(requests[0].ends_with("level=2&answer=14"));
+    }
+
+    #[test]
+    fn reports_submission_outcomes() {
+        let (url, server) = serve(vec![
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too high.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>That's not the right answer; your answer is too low.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>That's not the right answer. Please wait one minute.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>You gave an answer too recently. You have 1m 5s left to wait.</p></article>",
+            ),
+            (
+                200,
+                "<article><p>You don't seem to be solving the right level.</p></article>",
+            ),
+            (200, "<article><p>Something else.</p></article>"),
+        ]);
+        let client = client(&url);
+
+        assert!(matches!(
+            client.submit(puzzle(), 1, "9"),
+            Err(AocClientError::TooHigh)
+        ));
+        assert!(matches!(
+            client.submit(puzzle(), 1, "1"),
+            Err(AocClientError::TooLow)
+        ));
+        assert!(matches!(
+            client.submit(puzzle(), 1, "2"),
+            Err(AocClientError::WrongAnswer)
+        ));
+        assert!(matches!(
+            client.submit(puzzle(), 1, "3"),
+            Err(AocClientError::RateLimited(wait)) if wait == Duration::from_secs(65)
+        ));
+        assert!(matches!(
+            client.submit(puzzle(), 1, "3"),
+            Err(AocClientError::AlreadySolved)
+        ));
+        assert!(matches!(
+            client.submit(puzzle(), 1, "3"),
+            Err(AocClientError::UnknownResponse(_))
+        ));
+        server.join().unwrap();
+    }
+}
@@ -1,5 +1,17 @@
-use crate::template::{all_days, run_multi::run_multi};
+use std::process;
 
-pub fn handle(is_release: bool) {
-    run_multi(&all_days().collect(), is_release, false);
+use crate::template::runner::Timeouts;
+use crate::template::{Year, all_puzzles, run_multi::run_multi};
+
+pub fn handle(year: Year, is_release: bool, timeouts: Timeouts, jobs: usize) {
+    if let Err(e) = run_multi(
+        &all_puzzles(year).collect(),
+        is_release,
+        false,
+        timeouts,
+        jobs,
+    ) {
+        eprintln!("Failed to run the solutions: {e}");
+        process::exit(1);
+    }
 }
@@ -0,0 +1,157 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process;
+use std::time::Duration;
+
+use crate::template::commands::verify::Check;
+use crate::template::examples::parse_entry;
+use crate::template::run_multi::child_commands;
+use crate::template::runner::{InputSource, Status};
+use crate::template::{ANSI_BOLD, ANSI_RESET, PuzzleId};
+
+/// Extension of the file holding the expected answers of an input, e.g. `alice.answers` for `alice.txt`.
+const ANSWERS_EXTENSION: &str = "answers";
+
+/// Shown instead of the answer of a part that failed.
+const ERROR_PREFIX: &str = "error: ";
+
+/// Runs `puzzle` on every input in `dir` and checks the answers given in the matching answers files.
+///
+/// An answers file contains the expected answer of each part, e.g. `part_one: 40`, in the format of example front
+/// matter (see [`crate::template::examples`]).
+pub fn handle(puzzle: PuzzleId, release: bool, dir: &str) {
+    let inputs = match read_inputs(Path::new(dir)) {
+        Ok(inputs) if inputs.is_empty() => {
+            eprintln!("No inputs found in \"{dir}\".");
+            process::exit(1);
+        }
+        Ok(inputs) => inputs,
+        Err(e) => {
+            eprintln!("Failed to read inputs: {e}");
+            process::exit(1);
+        }
+    };
+
+    println!(
+        "{ANSI_BOLD}Running {puzzle} on {} inputs{ANSI_RESET}",
+        inputs.len()
+    );
+    println!();
+    println!("| Input | Part 1 | Part 2 |");
+    println!("| :--- | :---: | :---: |");
+
+    let mut failures = vec![];
+
+    for path in &inputs {
+        let name = path
+            .file_name()
+            .map_or_else(String::new, |name| name.to_string_lossy().to_string());
+
+        let answers = match read_answers(path) {
+            Ok(answers) => answers,
+            Err(e) => {
+                eprintln!("Failed to read answers of \"{name}\": {e}");
+                process::exit(1);
+            }
+        };
+
+        let input = InputSource::Path(path.to_string_lossy().to_string());
+        let mut actual: [Option<(Option<String>, Duration)>; 2] = [None, None];
+
+        let mut results = match child_commands::run_solution(puzzle, &input, release) {
+            Ok(results) => results,
+            Err(e) => {
+                eprintln!("Failed to run {puzzle} on \"{name}\": {e}");
+                process::exit(1);
+            }
+        };
+
+        for result in results.by_ref() {
+            if let Some(index) = result.part.checked_sub(1) {
+                let answer = match result.status {
+                    Status::Error(message) => Some(format!("{ERROR_PREFIX}{message}")),
+                    _ => result.answer,
+                };
+                actual[usize::from(index)] = Some((answer, result.duration));
+            }
+        }
+
+        // a solution that did not build or crashed reports no results at all.
+        if let Some(status) = results.failure() {
+            failures.push(format!(
+                "{name}: the solution exited unexpectedly ({status})."
+            ));
+        }
+
+        let cells = [1, 2].map(|part: u8| {
+            let Some((answer, duration)) = actual[usize::from(part - 1)].clone() else {
+                if part > puzzle.part_count() {
+                    return "-".to_string();
+                }
+                failures.push(format!("{name} part {part}: no result."));
+                return "✖ no result".to_string();
+            };
+
+            let key = if part == 1 { "part_one" } else { "part_two" };
+            let check = Check::new(answers.get(key).map(String::as_str), answer.as_deref());
+
+            if let Check::Fail { expected, actual } = &check {
+                failures.push(format!(
+                    "{name} part {part}: expected {expected}, got {}.",
+                    actual.as_deref().unwrap_or("no answer")
+                ));
+            } else if let Some(error) = answer.as_ref().filter(|a| a.starts_with(ERROR_PREFIX)) {
+                failures.push(format!("{name} part {part}: {error}."));
+            }
+
+            match answer {
+                Some(answer) => format!("`{answer}` {} ({duration:.1?})", check.symbol()),
+                None => format!("{} no answer", check.symbol()),
+            }
+        });
+
+        println!("| {name} | {} | {} |", cells[0], cells[1]);
+    }
+
+    println!();
+
+    if failures.is_empty() {
+        println!("All answers match.");
+        return;
+    }
+
+    for failure in &failures {
+        eprintln!("{failure}");
+    }
+    process::exit(1);
+}
+
+/// Every file in `dir` except answers files, sorted by name.
+fn read_inputs(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let mut inputs: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(|e| format!("{}: {e}", dir.display()))?
+        .filter_map(|entry| Some(entry.ok()?.path()))
+        .filter(|path| {
+            path.is_file()
+                && path
+                    .extension()
+                    .is_none_or(|extension| extension != ANSWERS_EXTENSION)
+        })
+        .collect();
+
+    inputs.sort_unstable();
+    Ok(inputs)
+}
+
+/// Reads the answers file of `input`. Returns no answers if there is none.
+fn read_answers(input: &Path) -> Result<HashMap<String, String>, String> {
+    let Ok(content) = fs::read_to_string(input.with_extension(ANSWERS_EXTENSION)) else {
+        return Ok(HashMap::new());
+    };
+
+    content
+        .lines()
+        .filter_map(|line| parse_entry(line).transpose())
+        .collect()
+}
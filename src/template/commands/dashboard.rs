@@ -0,0 +1,232 @@
+use std::io::{self, BufRead, Write};
+use std::path::Path;
+
+use crate::template::answers::Answers;
+use crate::template::commands::{scaffold, solve, time, watch};
+use crate::template::runner::{Format, InputSource, Timeouts};
+use crate::template::stats::Statistic;
+use crate::template::timings::Timings;
+use crate::template::{ANSI_BOLD, ANSI_RESET, Day, PuzzleId, Year, all_puzzles};
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+const HELP: &str = "[c]reate, [s]olve, [t]est or [b]enchmark a day, e.g. `s 5`. [r]efresh, [q]uit.";
+
+/// Something to do with a day, picked at the prompt.
+enum Action {
+    Scaffold(PuzzleId),
+    Solve(PuzzleId),
+    Test(PuzzleId),
+    Benchmark(PuzzleId),
+    Refresh,
+    Quit,
+}
+
+/// Shows every day of `year` with its stars, answers, timings and data files and runs the picked actions.
+///
+/// Reads one command per line, so it works in any terminal. Runs until `q` or the end of input.
+pub fn handle(year: Year) {
+    let stdin = io::stdin();
+    let mut lines = stdin.lock().lines();
+    let mut message = None;
+
+    loop {
+        print!("{CLEAR_SCREEN}");
+        print_calendar(year);
+        println!();
+        if let Some(message) = message.take() {
+            println!("{message}");
+        }
+        print!("{HELP}\n> ");
+        io::stdout().flush().unwrap();
+
+        let Some(Ok(line)) = lines.next() else {
+            println!();
+            return;
+        };
+
+        let action = match parse_action(year, &line) {
+            Ok(action) => action,
+            Err(e) => {
+                message = Some(e);
+                continue;
+            }
+        };
+
+        println!();
+        match action {
+            Action::Quit => return,
+            Action::Refresh => continue,
+            Action::Scaffold(puzzle) => {
+                // scaffolding exits if the module exists, which would end the dashboard.
+                if Path::new(&puzzle.bin_path()).exists() {
+                    message = Some(format!("{puzzle} already exists."));
+                    continue;
+                }
+                scaffold::handle(puzzle, false);
+            }
+            Action::Solve(puzzle) => {
+                if let Err(e) = solve::handle(
+                    puzzle,
+                    true,
+                    false,
+                    None,
+                    Format::Human,
+                    &InputSource::Puzzle,
+                ) {
+                    println!("\n{e}");
+                }
+            }
+            Action::Test(puzzle) => watch::run_bin_tests(puzzle),
+            // a quick look at the timings, stored results are only written by `cargo time --store`.
+            Action::Benchmark(puzzle) => {
+                if let Err(e) = time::handle(
+                    year,
+                    Some(puzzle),
+                    false,
+                    false,
+                    Statistic::default(),
+                    None,
+                    Timeouts::default(),
+                ) {
+                    println!("\n{e}");
+                }
+            }
+        }
+
+        print!("\nPress enter to return to the calendar.");
+        io::stdout().flush().unwrap();
+        if !matches!(lines.next(), Some(Ok(_))) {
+            println!();
+            return;
+        }
+    }
+}
+
+fn parse_action(year: Year, line: &str) -> Result<Action, String> {
+    let mut words = line.split_whitespace();
+    let command = words.next().unwrap_or("r");
+
+    let mut puzzle = || -> Result<PuzzleId, String> {
+        let day = words
+            .next()
+            .ok_or_else(|| format!("`{command}` expects a day."))?
+            .parse::<Day>()
+            .map_err(|e| e.to_string())?;
+        PuzzleId::new(year, day).ok_or_else(|| {
+            format!(
+                "the {year} event only lasts until day {}.",
+                year.last_day().into_inner()
+            )
+        })
+    };
+
+    match command {
+        "c" => Ok(Action::Scaffold(puzzle()?)),
+        "s" => Ok(Action::Solve(puzzle()?)),
+        "t" => Ok(Action::Test(puzzle()?)),
+        "b" => Ok(Action::Benchmark(puzzle()?)),
+        "r" => Ok(Action::Refresh),
+        "q" => Ok(Action::Quit),
+        x => Err(format!("Unknown command: {x}")),
+    }
+}
+
+fn print_calendar(year: Year) {
+    // the calendar only reads the ledger, so it is shown without answers if it is broken.
+    let (answers, ledger_error) = match Answers::read_from_file() {
+        Ok(answers) => (answers, None),
+        Err(e) => (Answers::default(), Some(e)),
+    };
+    let timings = Timings::read_from_file();
+    let statistic = Statistic::default();
+
+    let header = [
+        "Day", "Stars", "Part 1", "Part 2", "Time 1", "Time 2", "Input", "Example",
+    ]
+    .map(String::from);
+
+    let rows: Vec<[String; 8]> = all_puzzles(year)
+        .map(|puzzle| {
+            let timing = timings.data.iter().find(|t| t.puzzle() == puzzle);
+            let exists = |folder| {
+                let path = puzzle.data_path(folder, "txt");
+                if Path::new(&path).exists() {
+                    "✔"
+                } else {
+                    "-"
+                }
+                .to_string()
+            };
+
+            let part_answers = [1, 2].map(|part| last_answer(&answers, puzzle, part));
+            let part_times = [1, 2].map(|part| {
+                timing
+                    .and_then(|timing| timing.format_part(part, statistic))
+                    .unwrap_or_else(|| "-".into())
+            });
+            let stars: String = (1..=puzzle.part_count())
+                .map(|part| match answers.correct(puzzle, part) {
+                    Some(_) => '★',
+                    None => '☆',
+                })
+                .collect();
+
+            let [part_1, part_2] = part_answers;
+            let [time_1, time_2] = part_times;
+            [
+                puzzle.day().to_string(),
+                stars,
+                part_1,
+                part_2,
+                time_1,
+                time_2,
+                exists("inputs"),
+                exists("examples"),
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|column| {
+            rows.iter()
+                .chain([&header])
+                .map(|row| row[column].chars().count())
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+
+    let format_row = |row: &[String; 8]| {
+        row.iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{cell:<width$}"))
+            .collect::<Vec<_>>()
+            .join("  ")
+    };
+
+    println!("{ANSI_BOLD}Advent of Code {year}{ANSI_RESET}");
+    println!();
+    println!("{ANSI_BOLD}{}{ANSI_RESET}", format_row(&header));
+    for row in &rows {
+        println!("{}", format_row(row));
+    }
+
+    if let Some(e) = ledger_error {
+        println!();
+        println!("Failed to read the answer ledger: {e}");
+    }
+}
+
+/// The accepted answer of a part, or else the last one submitted.
+fn last_answer(answers: &Answers, puzzle: PuzzleId, part: u8) -> String {
+    let Some(part_answers) = answers.get(puzzle, part) else {
+        return "-".into();
+    };
+
+    part_answers
+        .correct
+        .as_ref()
+        .or_else(|| part_answers.submissions.last().map(|s| &s.answer))
+        .map_or_else(|| "-".into(), Clone::clone)
+}
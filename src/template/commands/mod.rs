@@ -1,6 +1,10 @@
 pub mod all;
+pub mod batch;
+pub mod dashboard;
 pub mod download;
 pub mod read;
 pub mod scaffold;
 pub mod solve;
 pub mod time;
+pub mod verify;
+pub mod watch;
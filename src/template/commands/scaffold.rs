@@ -1,14 +1,18 @@
 use std::{
-    fs::{File, OpenOptions},
+    fs::{self, File, OpenOptions},
     io::Write,
+    path::Path,
     process,
 };
 
-use crate::template::Day;
+use crate::template::PuzzleId;
 
 const MODULE_TEMPLATE: &str =
     include_str!(concat!(env!("CARGO_MANIFEST_DIR"), "/src/template.txt"));
 
+/// Front matter of new example files, see [`crate::template::examples`].
+const EXAMPLE_TEMPLATE: &str = "---\npart_one:\npart_two:\n---\n";
+
 fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error> {
     let mut file = OpenOptions::new();
     if overwrite {
@@ -20,6 +24,9 @@ fn safe_create_file(path: &str, overwrite: bool) -> Result<File, std::io::Error>
 }
 
 fn create_file(path: &str) -> Result<File, std::io::Error> {
+    if let Some(parent) = Path::new(path).parent() {
+        fs::create_dir_all(parent)?;
+    }
     OpenOptions::new()
         .write(true)
         .create(true)
@@ -27,10 +34,10 @@ fn create_file(path: &str) -> Result<File, std::io::Error> {
         .open(path)
 }
 
-pub fn handle(day: Day, overwrite: bool) {
-    let input_path = format!("data/inputs/{day}.txt");
-    let example_path = format!("data/examples/{day}.txt");
-    let module_path = format!("src/bin/{day}.rs");
+pub fn handle(puzzle: PuzzleId, overwrite: bool) {
+    let input_path = puzzle.data_path("inputs", "txt");
+    let example_path = puzzle.data_path("examples", "txt");
+    let module_path = format!("src/bin/{puzzle}.rs");
 
     let mut file = match safe_create_file(&module_path, overwrite) {
         Ok(file) => file,
@@ -42,7 +49,7 @@ pub fn handle(day: Day, overwrite: bool) {
 
     match file.write_all(
         MODULE_TEMPLATE
-            .replace("%DAY_NUMBER%", &day.into_inner().to_string())
+            .replace("%DAY_NUMBER%", &puzzle.day().into_inner().to_string())
             .as_bytes(),
     ) {
         Ok(()) => {
@@ -64,8 +71,10 @@ pub fn handle(day: Day, overwrite: bool) {
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
+    match create_file(&example_path)
+        .and_then(|mut file| file.write_all(EXAMPLE_TEMPLATE.as_bytes()))
+    {
+        Ok(()) => {
             println!("Created empty example file \"{}\"", &example_path);
         }
         Err(e) => {
@@ -75,5 +84,9 @@ pub fn handle(day: Day, overwrite: bool) {
     }
 
     println!("---");
-    println!("🎄 Type `cargo solve {day}` to run your solution.");
+    println!(
+        "🎄 Type `cargo solve {} --year {}` to run your solution.",
+        puzzle.day(),
+        puzzle.year()
+    );
 }
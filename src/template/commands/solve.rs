@@ -1,9 +1,18 @@
 use std::process::{Command, Stdio};
 
-use crate::template::Day;
+use crate::template::PuzzleId;
+use crate::template::runner::{Format, InputSource};
 
-pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
-    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), day.to_string()];
+/// Runs the solution of `puzzle` with `cargo run`, returns an error if it could not be started or failed.
+pub fn handle(
+    puzzle: PuzzleId,
+    release: bool,
+    dhat: bool,
+    submit_part: Option<u8>,
+    format: Format,
+    input: &InputSource,
+) -> Result<(), String> {
+    let mut cmd_args = vec!["run".to_string(), "--bin".to_string(), puzzle.to_string()];
 
     if dhat {
         cmd_args.extend([
@@ -23,12 +32,23 @@ pub fn handle(day: Day, release: bool, dhat: bool, submit_part: Option<u8>) {
         cmd_args.push(submit_part.to_string());
     }
 
-    let mut cmd = Command::new("cargo")
+    cmd_args.extend(input.to_args());
+
+    if format == Format::Json {
+        cmd_args.push("--format".to_string());
+        cmd_args.push("json".to_string());
+    }
+
+    let status = Command::new("cargo")
         .args(&cmd_args)
         .stdout(Stdio::inherit())
         .stderr(Stdio::inherit())
-        .spawn()
-        .unwrap();
+        .status()
+        .map_err(|e| format!("failed to run cargo: {e}"))?;
 
-    cmd.wait().unwrap();
+    if status.success() {
+        Ok(())
+    } else {
+        Err(format!("{puzzle} failed ({status})."))
+    }
 }
@@ -1,34 +1,75 @@
 use std::collections::HashSet;
 
+use crate::template::history::{self, History, Run};
 use crate::template::run_multi::run_multi;
-use crate::template::timings::Timings;
-use crate::template::{Day, all_days, readme_benchmarks};
+use crate::template::runner::Timeouts;
+use crate::template::stats::Statistic;
+use crate::template::timings::{Timing, Timings};
+use crate::template::{ANSI_BOLD, ANSI_RESET, PuzzleId, Year, all_puzzles, readme_benchmarks};
 
-pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
+/// Options of `cargo time --compare`.
+pub struct CompareOptions {
+    /// Commit to compare with, the last run on this machine if not set.
+    pub baseline: Option<String>,
+    /// Percentage a part may get slower before it is flagged.
+    pub threshold: f64,
+}
+
+/// Benchmarks the puzzles of `year`, or only `puzzle`. Returns an error if they could not be run or stored, or if
+/// any part regressed compared with the baseline.
+pub fn handle(
+    year: Year,
+    puzzle: Option<PuzzleId>,
+    run_all: bool,
+    store: bool,
+    statistic: Statistic,
+    compare: Option<CompareOptions>,
+    timeouts: Timeouts,
+) -> Result<(), String> {
     let stored_timings = Timings::read_from_file();
 
-    let days_to_run = day.map_or_else(
+    // a history that can't be read is neither compared with nor overwritten.
+    let mut history = if store || compare.is_some() {
+        History::read_from_file().map_err(|e| format!("Failed to read benchmark history: {e}"))?
+    } else {
+        History::default()
+    };
+
+    let puzzles_to_run = puzzle.map_or_else(
         || {
             if run_all {
-                all_days().collect()
+                all_puzzles(year).collect()
             } else {
                 // when the `--all` flag is not set, filter out days that are fully benched.
-                all_days()
-                    .filter(|day| !stored_timings.is_day_complete(*day))
+                all_puzzles(year)
+                    .filter(|puzzle| !stored_timings.is_day_complete(*puzzle))
                     .collect()
             }
         },
-        |day| HashSet::from([day]),
+        |puzzle| HashSet::from([puzzle]),
     );
 
-    let timings = run_multi(&days_to_run, true, true).unwrap();
+    let timings = run_multi(&puzzles_to_run, true, true, timeouts, 1)
+        .map_err(|e| format!("Failed to run the solutions: {e}"))?
+        .unwrap();
+
+    let threshold = compare.as_ref().map(|options| options.threshold);
+    let has_regressions = compare
+        .is_some_and(|options| print_comparison(&history, &timings.data, &options, statistic));
 
     if store {
+        history.runs.push(Run::current(timings.data.clone()));
+        if let Err(e) = history.store_file() {
+            eprintln!("Failed to store benchmark history: {e}");
+        }
+
         let merged_timings = stored_timings.merge(&timings);
-        merged_timings.store_file().unwrap();
+        merged_timings
+            .store_file()
+            .map_err(|e| format!("Failed to store timings: {e}"))?;
 
         println!();
-        match readme_benchmarks::update(merged_timings) {
+        match readme_benchmarks::update(merged_timings, statistic) {
             Ok(()) => {
                 println!("Stored updated benchmarks.");
             }
@@ -37,4 +78,71 @@ pub fn handle(day: Option<Day>, run_all: bool, store: bool) {
             }
         }
     }
+
+    if has_regressions {
+        return Err(format!(
+            "Some parts got more than {}% slower.",
+            threshold.unwrap_or_default()
+        ));
+    }
+    Ok(())
+}
+
+/// Prints how each part changed compared with the baseline, returns `true` if any part regressed.
+fn print_comparison(
+    history: &History,
+    timings: &[Timing],
+    options: &CompareOptions,
+    statistic: Statistic,
+) -> bool {
+    let machine = history::machine_id();
+    let comparisons = history::compare(
+        history,
+        timings,
+        &machine,
+        options.baseline.as_deref(),
+        statistic,
+    );
+
+    println!();
+
+    if comparisons.is_empty() {
+        println!("No earlier runs to compare with.");
+        return false;
+    }
+
+    let baseline = match &options.baseline {
+        Some(baseline) => format!("commit {baseline}"),
+        None => format!("the last run on {machine}"),
+    };
+    println!("{ANSI_BOLD}Compared with {baseline} ({statistic}){ANSI_RESET}");
+    println!();
+    println!("| Day | Part | Before | After | Change |");
+    println!("| :---: | :---: | :---: | :---: | :---: |");
+
+    let mut has_regressions = false;
+
+    for comparison in &comparisons {
+        let is_regression = comparison.is_regression(options.threshold);
+        has_regressions |= is_regression;
+
+        let part = match comparison.part {
+            0 => "Parse".to_string(),
+            part => part.to_string(),
+        };
+        println!(
+            "| {} | {part} | {:.1?} | {:.1?} | {:+.1}%{} |",
+            comparison.puzzle.day(),
+            comparison.before,
+            comparison.after,
+            comparison.change(),
+            if is_regression { " ✖" } else { "" }
+        );
+    }
+
+    if has_regressions {
+        eprintln!("\nSome parts got more than {}% slower.", options.threshold);
+    }
+
+    has_regressions
 }
@@ -0,0 +1,123 @@
+use std::process;
+
+use crate::template::answers::Answers;
+use crate::template::run_multi;
+use crate::template::runner::{Status, Timeouts};
+use crate::template::timings::TIMEOUT;
+use crate::template::{ANSI_BOLD, ANSI_RESET, PuzzleId, Year, all_puzzles};
+
+/// Outcome of comparing one part against its accepted answer.
+pub(crate) enum Check {
+    Pass,
+    Fail {
+        expected: String,
+        actual: Option<String>,
+    },
+    /// No accepted answer is known for this part.
+    Unchecked,
+}
+
+impl Check {
+    pub(crate) fn new(expected: Option<&str>, actual: Option<&str>) -> Self {
+        match expected {
+            None => Check::Unchecked,
+            Some(expected) if actual == Some(expected) => Check::Pass,
+            Some(expected) => Check::Fail {
+                expected: expected.into(),
+                actual: actual.map(Into::into),
+            },
+        }
+    }
+
+    pub(crate) fn symbol(&self) -> &'static str {
+        match self {
+            Check::Pass => "✔",
+            Check::Fail { .. } => "✖",
+            Check::Unchecked => "-",
+        }
+    }
+}
+
+/// Runs every day with an accepted answer in `data/answers.json` and compares its answers.
+/// Days that exceed `timeouts` are stopped and fail.
+pub fn handle(year: Year, timeouts: Timeouts) {
+    let answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Failed to read the answer ledger: {e}");
+            process::exit(1);
+        }
+    };
+
+    let solved: Vec<PuzzleId> = all_puzzles(year)
+        .filter(|puzzle| {
+            (1..=puzzle.part_count()).any(|part| answers.correct(*puzzle, part).is_some())
+        })
+        .collect();
+
+    if solved.is_empty() {
+        println!("No accepted answers stored for {year}, nothing to verify.");
+        return;
+    }
+
+    println!("{ANSI_BOLD}Verifying {year}{ANSI_RESET}");
+    println!();
+    println!("| Day | Part 1 | Part 2 |");
+    println!("| :---: | :---: | :---: |");
+
+    let mut failures = vec![];
+
+    let run = run_multi::run_each(&solved, true, timeouts, |puzzle, results| {
+        let mut actual = [None, None];
+        for result in results {
+            if let Some(index) = result.part.checked_sub(1) {
+                actual[usize::from(index)] = match result.status {
+                    Status::Error(message) => Some(format!("error: {message}")),
+                    Status::Timeout => Some(TIMEOUT.to_string()),
+                    _ => result.answer,
+                };
+            }
+        }
+
+        let checks = [1, 2].map(|part: u8| {
+            Check::new(
+                answers.correct(puzzle, part),
+                actual[usize::from(part - 1)].as_deref(),
+            )
+        });
+
+        println!(
+            "| {} | {} | {} |",
+            puzzle.day(),
+            checks[0].symbol(),
+            checks[1].symbol()
+        );
+
+        for (part, check) in (1..=2).zip(checks) {
+            if let Check::Fail { expected, actual } = check {
+                failures.push((puzzle, part, expected, actual));
+            }
+        }
+    });
+
+    if let Err(e) = run {
+        eprintln!("Failed to run the solutions: {e}");
+        process::exit(1);
+    }
+
+    println!();
+
+    if failures.is_empty() {
+        println!("All answers match.");
+        return;
+    }
+
+    for (puzzle, part, expected, actual) in &failures {
+        eprintln!(
+            "Day {} part {part}: expected {expected}, got {}.",
+            puzzle.day(),
+            actual.as_deref().unwrap_or("no answer")
+        );
+    }
+    process::exit(1);
+}
@@ -0,0 +1,101 @@
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use std::{fs, thread};
+
+use crate::template::commands::solve;
+use crate::template::examples::is_example_of;
+use crate::template::runner::{Format, InputSource};
+use crate::template::{ANSI_BOLD, ANSI_RESET, PuzzleId};
+
+/// How often the watched files are checked for changes.
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+const CLEAR_SCREEN: &str = "\x1b[2J\x1b[H";
+
+/// Reruns the tests and/or the solution of `puzzle` whenever its source, input or examples change.
+///
+/// Files are polled, so this works without file system notifications. Runs until interrupted.
+pub fn handle(puzzle: PuzzleId, release: bool, run_tests: bool, run_solution: bool) {
+    let mut last_snapshot = None;
+
+    loop {
+        let snapshot = snapshot(puzzle);
+
+        if last_snapshot.as_ref() != Some(&snapshot) {
+            print!("{CLEAR_SCREEN}");
+            println!("{ANSI_BOLD}Watching {puzzle}{ANSI_RESET} (press Ctrl-C to stop)");
+            println!();
+
+            if run_tests {
+                run_bin_tests(puzzle);
+            }
+
+            if run_solution
+                && let Err(e) = solve::handle(
+                    puzzle,
+                    release,
+                    false,
+                    None,
+                    Format::Human,
+                    &InputSource::Puzzle,
+                )
+            {
+                eprintln!("Error: {e}");
+            }
+
+            // changes made while running are picked up by the next poll.
+            last_snapshot = Some(snapshot);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+pub(crate) fn run_bin_tests(puzzle: PuzzleId) {
+    let status = Command::new("cargo")
+        .args(["test", "--quiet", "--bin", &puzzle.to_string()])
+        .status();
+
+    if let Err(e) = status {
+        eprintln!("Failed to run tests: {e}");
+    }
+    println!();
+}
+
+/// Modification times of every watched file of `puzzle`, `None` for files that don't exist (yet).
+fn snapshot(puzzle: PuzzleId) -> Vec<(PathBuf, Option<SystemTime>)> {
+    let mut paths: Vec<PathBuf> = [
+        puzzle.bin_path(),
+        puzzle.data_path("inputs", "txt"),
+        puzzle.data_path("params", "txt"),
+    ]
+    .into_iter()
+    .map(PathBuf::from)
+    .collect();
+
+    // examples are listed on every poll, so new ones like `DD-2.txt` are watched as well.
+    let example_path = puzzle.data_path("examples", "txt");
+    let example_path = Path::new(&example_path);
+    if let Some(dir) = example_path.parent()
+        && let Ok(entries) = fs::read_dir(dir)
+    {
+        let day = puzzle.day().to_string();
+        paths.extend(
+            entries
+                .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+                .filter(|name| is_example_of(name, &day))
+                .map(|name| dir.join(name)),
+        );
+    }
+
+    paths.sort_unstable();
+
+    paths
+        .into_iter()
+        .map(|path| {
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            (path, modified)
+        })
+        .collect()
+}
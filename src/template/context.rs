@@ -0,0 +1,111 @@
+//! Parameters that differ between inputs, e.g. the number of iterations the example of a puzzle runs.
+//!
+//! Examples set them in their front matter, next to the expected answers (see [`crate::template::examples`]):
+//!
+//! ```text
+//! ---
+//! part_one: 40
+//! connections: 10
+//! ---
+//! ```
+//!
+//! The real input reads them from `data/<year>/params/DD.txt`, which contains one `key: value` per line.
+//! Solutions usually fall back to the value of the real input with [`Context::get_or`].
+
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::str::FromStr;
+use std::{env, fs};
+
+use crate::template::PuzzleId;
+use crate::template::examples::{Example, parse_entry};
+
+/// The parameters of the input a solution runs on.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Context {
+    params: HashMap<String, String>,
+}
+
+impl Context {
+    #[must_use]
+    pub fn new(params: HashMap<String, String>) -> Self {
+        Self { params }
+    }
+
+    /// Reads the parameters of the real input of `puzzle`. Returns an empty context if there are none.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameter file exists but can't be parsed.
+    #[must_use]
+    pub fn for_input(puzzle: PuzzleId) -> Self {
+        let path = env::current_dir()
+            .unwrap()
+            .join(puzzle.data_path("params", "txt"));
+
+        let Ok(content) = fs::read_to_string(&path) else {
+            return Self::default();
+        };
+
+        let params: Result<HashMap<String, String>, String> = content
+            .lines()
+            .filter_map(|line| parse_entry(line).transpose())
+            .collect();
+
+        Self::new(params.unwrap_or_else(|e| panic!("{}: {e}", path.display())))
+    }
+
+    /// The parameters of an example, taken from its front matter.
+    #[must_use]
+    pub fn for_example(example: &Example) -> Self {
+        Self::new(example.metadata.clone())
+    }
+
+    /// Returns the parameter `key`, or `None` if it's not set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameter can't be parsed as `T`.
+    #[must_use]
+    pub fn get<T: FromStr<Err: Debug>>(&self, key: &str) -> Option<T> {
+        self.params.get(key).map(|value| {
+            value
+                .parse()
+                .unwrap_or_else(|e| panic!("invalid parameter `{key}: {value}`: {e:?}"))
+        })
+    }
+
+    /// Returns the parameter `key`, or `default` if it's not set.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameter can't be parsed as `T`.
+    #[must_use]
+    pub fn get_or<T: FromStr<Err: Debug>>(&self, key: &str, default: T) -> T {
+        self.get(key).unwrap_or(default)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::Context;
+
+    #[test]
+    fn reads_parameters() {
+        let context = Context::new(HashMap::from([("connections".into(), "10".into())]));
+        assert_eq!(context.get::<usize>("connections"), Some(10));
+        assert_eq!(context.get::<usize>("iterations"), None);
+        assert_eq!(context.get_or("iterations", 1000), 1000);
+    }
+
+    #[test]
+    #[should_panic]
+    fn panics_on_invalid_parameters() {
+        let context = Context::new(HashMap::from([("connections".into(), "ten".into())]));
+        let _: Option<usize> = context.get("connections");
+    }
+}
@@ -2,14 +2,12 @@ use std::error::Error;
 use std::fmt::Display;
 use std::str::FromStr;
 
-#[cfg(feature = "today")]
-use chrono::{Datelike, FixedOffset, Utc};
-
-#[cfg(feature = "today")]
-const SERVER_UTC_OFFSET: i32 = -5;
+use crate::template::Year;
 
 /// A valid day number of advent (i.e. an integer in range 1 to 25).
 ///
+/// Not every year's event lasts 25 days, see [`Year::last_day`].
+///
 /// # Display
 /// This value displays as a two digit number.
 ///
@@ -32,25 +30,11 @@ impl Day {
     }
 
     /// Converts the [`Day`] into an [`u8`].
-    pub fn into_inner(self) -> u8 {
+    pub const fn into_inner(self) -> u8 {
         self.0
     }
 }
 
-#[cfg(feature = "today")]
-impl Day {
-    /// Returns the current day if it's between the 1st and the 25th of december, `None` otherwise.
-    pub fn today() -> Option<Self> {
-        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
-        let today = Utc::now().with_timezone(&offset);
-        if today.month() == 12 && today.day() <= 25 {
-            Self::new(u8::try_from(today.day()).ok()?)
-        } else {
-            None
-        }
-    }
-}
-
 impl Display for Day {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{:02}", self.0)
@@ -94,20 +78,24 @@ impl Display for DayFromStrError {
 
 /* -------------------------------------------------------------------------- */
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
-pub fn all_days() -> AllDays {
-    AllDays::new()
+/// An iterator that yields every day of `year`'s event, see [`Year::last_day`].
+pub fn all_days(year: Year) -> AllDays {
+    year.days()
 }
 
-/// An iterator that yields every day of advent from the 1st to the 25th.
+/// An iterator that yields every day of advent from the 1st to a last day.
 pub struct AllDays {
     current: u8,
+    last: u8,
 }
 
 impl AllDays {
-    #[allow(clippy::new_without_default)]
-    pub fn new() -> Self {
-        Self { current: 1 }
+    /// Creates an iterator that stops after `last`, use [`all_days`] for the days of an event.
+    pub(crate) fn until(last: Day) -> Self {
+        Self {
+            current: 1,
+            last: last.0,
+        }
     }
 }
 
@@ -115,10 +103,10 @@ impl Iterator for AllDays {
     type Item = Day;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.current > 25 {
+        if self.current > self.last {
             return None;
         }
-        // NOTE: the iterator starts at 1 and we have verified that the value is not above 25.
+        // NOTE: the iterator starts at 1 and `last` is a valid day, so the value is not above 25.
         let day = Day(self.current);
         self.current += 1;
 
@@ -144,38 +132,23 @@ macro_rules! day {
 #[cfg(feature = "test_lib")]
 mod tests {
     use super::{Day, all_days};
+    use crate::year;
 
     #[test]
     fn all_days_iterator() {
-        let mut iter = all_days();
-
-        assert_eq!(iter.next(), Some(Day(1)));
-        assert_eq!(iter.next(), Some(Day(2)));
-        assert_eq!(iter.next(), Some(Day(3)));
-        assert_eq!(iter.next(), Some(Day(4)));
-        assert_eq!(iter.next(), Some(Day(5)));
-        assert_eq!(iter.next(), Some(Day(6)));
-        assert_eq!(iter.next(), Some(Day(7)));
-        assert_eq!(iter.next(), Some(Day(8)));
-        assert_eq!(iter.next(), Some(Day(9)));
-        assert_eq!(iter.next(), Some(Day(10)));
-        assert_eq!(iter.next(), Some(Day(11)));
-        assert_eq!(iter.next(), Some(Day(12)));
-        assert_eq!(iter.next(), Some(Day(13)));
-        assert_eq!(iter.next(), Some(Day(14)));
-        assert_eq!(iter.next(), Some(Day(15)));
-        assert_eq!(iter.next(), Some(Day(16)));
-        assert_eq!(iter.next(), Some(Day(17)));
-        assert_eq!(iter.next(), Some(Day(18)));
-        assert_eq!(iter.next(), Some(Day(19)));
-        assert_eq!(iter.next(), Some(Day(20)));
-        assert_eq!(iter.next(), Some(Day(21)));
-        assert_eq!(iter.next(), Some(Day(22)));
-        assert_eq!(iter.next(), Some(Day(23)));
-        assert_eq!(iter.next(), Some(Day(24)));
-        assert_eq!(iter.next(), Some(Day(25)));
+        let mut iter = all_days(year!(2015));
+
+        for day in 1..=25 {
+            assert_eq!(iter.next(), Some(Day(day)));
+        }
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn all_days_stops_at_the_end_of_the_event() {
+        assert_eq!(all_days(year!(2025)).last(), Some(Day(12)));
+        assert_eq!(all_days(year!(2024)).last(), Some(Day(25)));
+    }
 }
 
 /* -------------------------------------------------------------------------- */
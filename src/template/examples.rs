@@ -0,0 +1,226 @@
+//! Example inputs annotated with their expected answers.
+//!
+//! An example file may start with a front matter header that lists the expected answer of each part:
+//!
+//! ```text
+//! ---
+//! part_one: 3
+//! part_two: 6
+//! ---
+//! L68
+//! L30
+//! ```
+//!
+//! Parts without a value are not checked. Other keys are passed to the solution as parameters, see
+//! [`crate::template::context`]. Besides `DD.txt`, a day can have several examples named like
+//! `DD-2.txt` or `DD-larger.txt`, which the test harness generated by `solution!` runs as well.
+
+use std::collections::HashMap;
+use std::path::Path;
+use std::{env, fs};
+
+use crate::template::PuzzleId;
+use crate::template::context::Context;
+use crate::template::registry::Solution;
+use crate::template::runner::{Format, PARSE_PART, Status};
+
+const FRONT_MATTER_DELIMITER: &str = "---";
+
+/// An example input and the values of its front matter.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Example {
+    /// File name of the example, e.g. `01-2.txt`.
+    pub name: String,
+    pub input: String,
+    pub metadata: HashMap<String, String>,
+}
+
+impl Example {
+    /// Parses the front matter of `content`, if there is one.
+    pub fn parse(name: &str, content: &str) -> Result<Self, String> {
+        let (metadata, input) = split_front_matter(content)?;
+
+        Ok(Self {
+            name: name.into(),
+            input: input.into(),
+            metadata,
+        })
+    }
+
+    /// The expected answer of `part`, e.g. `part_one: 3` for part 1.
+    #[must_use]
+    pub fn answer(&self, part: u8) -> Option<&str> {
+        let key = match part {
+            1 => "part_one",
+            2 => "part_two",
+            _ => return None,
+        };
+        self.metadata.get(key).map(String::as_str)
+    }
+}
+
+/// Splits `content` into the values of its front matter and the input that follows it.
+/// Content without front matter is returned as is.
+pub fn split_front_matter(content: &str) -> Result<(HashMap<String, String>, &str), String> {
+    let mut metadata = HashMap::new();
+
+    let Some(rest) = content
+        .strip_prefix(FRONT_MATTER_DELIMITER)
+        .and_then(|rest| rest.strip_prefix('\n').or(rest.strip_prefix("\r\n")))
+    else {
+        return Ok((metadata, content));
+    };
+
+    let mut offset = 0;
+
+    for line in rest.split_inclusive('\n') {
+        offset += line.len();
+
+        if line.trim() == FRONT_MATTER_DELIMITER {
+            return Ok((metadata, &rest[offset..]));
+        }
+
+        if let Some((key, value)) = parse_entry(line)? {
+            metadata.insert(key, value);
+        }
+    }
+
+    Err("Front matter is not closed with `---`.".into())
+}
+
+/// Parses a `key: value` line. Returns `None` for empty lines, comments starting with `#` and keys without a value.
+pub(crate) fn parse_entry(line: &str) -> Result<Option<(String, String)>, String> {
+    let line = line.trim();
+    if line.is_empty() || line.starts_with('#') {
+        return Ok(None);
+    }
+
+    let (key, value) = line
+        .split_once(':')
+        .ok_or(format!("Expected `key: value`, got `{line}`."))?;
+
+    let value = value.trim();
+    Ok((!value.is_empty()).then(|| (key.trim().to_string(), value.to_string())))
+}
+
+/// Returns `content` without its front matter.
+#[must_use]
+pub fn strip_front_matter(content: &str) -> &str {
+    split_front_matter(content).map_or(content, |(_, input)| input)
+}
+
+/// Reads all examples of `puzzle`, i.e. `DD.txt` and `DD-*.txt`, sorted by name.
+pub fn read_examples(puzzle: PuzzleId) -> Result<Vec<Example>, String> {
+    let cwd = env::current_dir().map_err(|e| e.to_string())?;
+    let path = cwd.join(puzzle.example_path(None));
+    let dir = path.parent().unwrap_or(Path::new("."));
+
+    let Ok(entries) = fs::read_dir(dir) else {
+        return Ok(vec![]);
+    };
+
+    let day = puzzle.day().to_string();
+    let mut names: Vec<String> = entries
+        .filter_map(|entry| entry.ok()?.file_name().into_string().ok())
+        .filter(|name| is_example_of(name, &day))
+        .collect();
+    names.sort_unstable();
+
+    names
+        .into_iter()
+        .map(|name| {
+            let content =
+                fs::read_to_string(dir.join(&name)).map_err(|e| format!("{name}: {e}"))?;
+            Example::parse(&name, &content).map_err(|e| format!("{name}: {e}"))
+        })
+        .collect()
+}
+
+/// Returns `true` if the file `name` is an example of `day`, e.g. `01.txt` or `01-2.txt` for day `01`.
+pub(crate) fn is_example_of(name: &str, day: &str) -> bool {
+    name.strip_suffix(".txt")
+        .and_then(|stem| stem.strip_prefix(day))
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+}
+
+/// Runs `solution` on every example of its puzzle and checks the answers given in the front matter.
+///
+/// # Panics
+///
+/// Panics with a list of mismatches if any part returns a different answer than expected.
+pub fn check(solution: &Solution) {
+    let examples = read_examples(solution.puzzle).unwrap_or_else(|e| panic!("{e}"));
+    let mut failures = vec![];
+
+    for example in &examples {
+        let context = Context::for_example(example);
+        let results = solution.run(&example.input, &context, false, Format::Human);
+
+        for part in solution.parts {
+            let Some(expected) = example.answer(*part) else {
+                continue;
+            };
+
+            // a part has no result if the parse step failed.
+            let result = results
+                .iter()
+                .find(|result| result.part == *part)
+                .or_else(|| results.iter().find(|result| result.part == PARSE_PART));
+            let actual = match result.map(|result| (&result.status, &result.answer)) {
+                Some((Status::Error(message), _)) => format!("error `{message}`"),
+                Some((_, Some(answer))) => format!("`{answer}`"),
+                _ => "no answer".into(),
+            };
+
+            if actual != format!("`{expected}`") {
+                failures.push(format!(
+                    "{} part {part}: expected `{expected}`, got {actual}",
+                    example.name
+                ));
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "\n{}\n", failures.join("\n"));
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{Example, is_example_of, strip_front_matter};
+
+    #[test]
+    fn parses_front_matter() {
+        let example =
+            Example::parse("01.txt", "---\npart_one: 3\npart_two:\n---\nL68\nL30\n").unwrap();
+        assert_eq!(example.input, "L68\nL30\n");
+        assert_eq!(example.answer(1), Some("3"));
+        assert_eq!(example.answer(2), None);
+    }
+
+    #[test]
+    fn keeps_input_without_front_matter() {
+        let example = Example::parse("01.txt", "L68\n---\nL30\n").unwrap();
+        assert_eq!(example.input, "L68\n---\nL30\n");
+        assert!(example.metadata.is_empty());
+    }
+
+    #[test]
+    fn rejects_invalid_front_matter() {
+        assert!(Example::parse("01.txt", "---\npart_one 3\n---\n").is_err());
+        assert!(Example::parse("01.txt", "---\npart_one: 3\n").is_err());
+        assert_eq!(
+            strip_front_matter("---\npart_one: 3\n"),
+            "---\npart_one: 3\n"
+        );
+    }
+
+    #[test]
+    fn matches_example_files() {
+        assert!(is_example_of("01.txt", "01"));
+        assert!(is_example_of("01-2.txt", "01"));
+        assert!(!is_example_of("011.txt", "01"));
+        assert!(!is_example_of("01.md", "01"));
+    }
+}
@@ -0,0 +1,445 @@
+//! Keeps every stored benchmark run, so later runs can be compared against earlier ones.
+
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind};
+use std::process::Command;
+use std::str::FromStr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use std::{env, fs};
+
+use tinyjson::JsonValue;
+
+use crate::template::PuzzleId;
+use crate::template::stats::Statistic;
+use crate::template::timings::{Timing, timings_in_event};
+
+static HISTORY_FILE_PATH: &str = "./data/timings_history.json";
+
+/// A benchmark run of one or more days, with the environment it was recorded in.
+#[derive(Clone, Debug)]
+pub struct Run {
+    /// Abbreviated hash of the checked out git commit, suffixed with `-dirty` if there were uncommitted changes.
+    pub commit: Option<String>,
+    /// UTC date and time of the run, e.g. `2025-12-08T06:12:43Z`.
+    pub date: String,
+    /// Output of `rustc --version`.
+    pub rustc: Option<String>,
+    /// `AOC_MACHINE_ID` if set, the host name otherwise.
+    pub machine: String,
+    pub timings: Vec<Timing>,
+}
+
+impl Run {
+    /// Records `timings` with the current commit, date, compiler and machine.
+    pub fn current(timings: Vec<Timing>) -> Self {
+        let commit = command_output("git", &["rev-parse", "--short", "HEAD"]).map(|commit| {
+            match command_output("git", &["status", "--porcelain"]) {
+                Some(_) => format!("{commit}-dirty"),
+                None => commit,
+            }
+        });
+
+        let now = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or_default();
+
+        Self {
+            commit,
+            date: format_date(now),
+            rustc: command_output("rustc", &["--version"]),
+            machine: machine_id(),
+            timings,
+        }
+    }
+}
+
+/// Every stored benchmark run, oldest first.
+/// Can be serialized from / to JSON.
+#[derive(Clone, Debug, Default)]
+pub struct History {
+    pub runs: Vec<Run>,
+}
+
+impl History {
+    /// Dehydrate the history to a JSON file.
+    pub fn store_file(&self) -> Result<(), Error> {
+        let json = JsonValue::from(self);
+        let mut file = fs::File::create(HISTORY_FILE_PATH)?;
+        json.format_to(&mut file)
+    }
+
+    /// Rehydrate the history from a JSON file. If not present, returns an empty history.
+    ///
+    /// A file that can't be read or parsed is an error, so it is never overwritten by a history missing its runs.
+    pub fn read_from_file() -> Result<Self, String> {
+        match fs::read_to_string(HISTORY_FILE_PATH) {
+            Ok(content) => History::try_from(content),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Finds the timing of `puzzle` to compare against.
+    ///
+    /// Without a `baseline`, this is the latest run on `machine` that timed the puzzle.
+    /// Otherwise, it's the latest run of a commit starting with `baseline`.
+    pub fn baseline_for(
+        &self,
+        puzzle: PuzzleId,
+        machine: &str,
+        baseline: Option<&str>,
+    ) -> Option<&Timing> {
+        self.runs
+            .iter()
+            .rev()
+            .filter(|run| match baseline {
+                Some(baseline) => run
+                    .commit
+                    .as_deref()
+                    .is_some_and(|commit| commit.starts_with(baseline)),
+                None => run.machine == machine,
+            })
+            .find_map(|run| run.timings.iter().find(|t| t.puzzle() == puzzle))
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// The change of a part's duration between a baseline and the current run.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Comparison {
+    pub puzzle: PuzzleId,
+    /// The part number, 0 for the parse step.
+    pub part: u8,
+    pub before: Duration,
+    pub after: Duration,
+}
+
+impl Comparison {
+    /// Relative change in percent, positive if the part got slower.
+    pub fn change(&self) -> f64 {
+        let before = self.before.as_secs_f64();
+        if before == 0.0 {
+            return 0.0;
+        }
+        (self.after.as_secs_f64() - before) / before * 100.0
+    }
+
+    /// Returns `true` if the part got slower by more than `threshold` percent.
+    pub fn is_regression(&self, threshold: f64) -> bool {
+        self.change() > threshold
+    }
+}
+
+/// Compares `statistic` of every part in `timings` with its baseline in `history`.
+/// Parts without statistics on either side are skipped.
+pub fn compare(
+    history: &History,
+    timings: &[Timing],
+    machine: &str,
+    baseline: Option<&str>,
+    statistic: Statistic,
+) -> Vec<Comparison> {
+    let mut comparisons = vec![];
+
+    for timing in timings {
+        let Some(previous) = history.baseline_for(timing.puzzle(), machine, baseline) else {
+            continue;
+        };
+
+        for (part, (before, after)) in (0..).zip(previous.stats.iter().zip(&timing.stats)) {
+            if let (Some(before), Some(after)) = (before, after) {
+                comparisons.push(Comparison {
+                    puzzle: timing.puzzle(),
+                    part,
+                    before: before.get(statistic),
+                    after: after.get(statistic),
+                });
+            }
+        }
+    }
+
+    comparisons
+}
+
+/// `AOC_MACHINE_ID` if set, the host name otherwise.
+pub fn machine_id() -> String {
+    env::var("AOC_MACHINE_ID")
+        .ok()
+        .or_else(|| fs::read_to_string("/etc/hostname").ok())
+        .or_else(|| command_output("hostname", &[]))
+        .map(|id| id.trim().to_string())
+        .filter(|id| !id.is_empty())
+        .unwrap_or_else(|| "unknown".into())
+}
+
+/// Runs a command and returns its trimmed output, or `None` if it failed or printed nothing.
+fn command_output(program: &str, args: &[&str]) -> Option<String> {
+    let output = Command::new(program).args(args).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let output = String::from_utf8(output.stdout).ok()?.trim().to_string();
+    (!output.is_empty()).then_some(output)
+}
+
+/// Formats a duration since the UNIX epoch as UTC date and time.
+fn format_date(since_epoch: Duration) -> String {
+    let secs = since_epoch.as_secs();
+    let (days, secs_of_day) = (secs / 86_400, secs % 86_400);
+
+    // civil date from days since the epoch, see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let z = days + 719_468;
+    let era = z / 146_097;
+    let day_of_era = z - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + u64::from(month <= 2);
+
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs_of_day / 3600,
+        secs_of_day / 60 % 60,
+        secs_of_day % 60
+    )
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&History> for JsonValue {
+    fn from(value: &History) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "runs".into(),
+            JsonValue::Array(value.runs.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<String> for History {
+    type Error = String;
+
+    fn try_from(value: String) -> Result<Self, Self::Error> {
+        let json = JsonValue::from_str(&value).or(Err("not valid JSON file."))?;
+
+        let json_runs = json
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("expected JSON document to be an object.")?
+            .get("runs")
+            .ok_or("expected JSON document to have key `runs`.")?
+            .get::<Vec<JsonValue>>()
+            .ok_or("expected `json.runs` to be an array.")?;
+
+        Ok(History {
+            runs: json_runs
+                .iter()
+                .map(Run::try_from)
+                .collect::<Result<_, _>>()?,
+        })
+    }
+}
+
+impl From<&Run> for JsonValue {
+    fn from(value: &Run) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        let optional = |value: &Option<String>| match value {
+            Some(value) => JsonValue::String(value.clone()),
+            None => JsonValue::Null,
+        };
+
+        map.insert("commit".into(), optional(&value.commit));
+        map.insert("date".into(), JsonValue::String(value.date.clone()));
+        map.insert("rustc".into(), optional(&value.rustc));
+        map.insert("machine".into(), JsonValue::String(value.machine.clone()));
+        map.insert(
+            "timings".into(),
+            JsonValue::Array(value.timings.iter().map(JsonValue::from).collect()),
+        );
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Run {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected run to be a JSON object.")?;
+
+        let optional = |key: &str| {
+            json.get(key)
+                .map(|v| if v.is_null() { None } else { v.get::<String>() })
+                .ok_or(format!("Expected run.{key} to be null or string."))
+                .map(|v| v.cloned())
+        };
+
+        let string = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<String>())
+                .cloned()
+                .ok_or(format!("Expected run.{key} to be a string."))
+        };
+
+        let timings = timings_in_event(
+            json.get("timings")
+                .and_then(|v| v.get::<Vec<JsonValue>>())
+                .ok_or("Expected run.timings to be an array.")?,
+        )?;
+
+        Ok(Run {
+            commit: optional("commit")?,
+            date: string("date")?,
+            rustc: optional("rustc")?,
+            machine: string("machine")?,
+            timings,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::{Comparison, History, Run, compare, format_date};
+    use crate::template::stats::{Statistic, Stats};
+    use crate::template::timings::Timing;
+    use crate::{day, template::PuzzleId, year};
+
+    fn timing(day: u8, part_1: u64, part_2: Option<u64>) -> Timing {
+        let stats = |millis: u64| Stats::single(Duration::from_millis(millis));
+        Timing {
+            year: year!(2025),
+            day: crate::template::Day::new(day).unwrap(),
+            parse: None,
+            part_1: Some(format!("{part_1}ms")),
+            part_2: part_2.map(|millis| format!("{millis}ms")),
+            total_nanos: 0_f64,
+            stats: [None, Some(stats(part_1)), part_2.map(stats)],
+        }
+    }
+
+    fn run(commit: &str, machine: &str, timings: Vec<Timing>) -> Run {
+        Run {
+            commit: Some(commit.into()),
+            date: "2025-12-01T00:00:00Z".into(),
+            rustc: Some("rustc 1.91.0".into()),
+            machine: machine.into(),
+            timings,
+        }
+    }
+
+    fn get_mock_history() -> History {
+        History {
+            runs: vec![
+                run(
+                    "aaaaaaa",
+                    "laptop",
+                    vec![timing(1, 10, Some(20)), timing(2, 5, None)],
+                ),
+                run("bbbbbbb", "desktop", vec![timing(1, 1, Some(2))]),
+                run("ccccccc", "laptop", vec![timing(1, 12, Some(20))]),
+            ],
+        }
+    }
+
+    #[test]
+    fn formats_dates() {
+        assert_eq!(format_date(Duration::ZERO), "1970-01-01T00:00:00Z");
+        assert_eq!(
+            format_date(Duration::from_secs(1_765_174_363)),
+            "2025-12-08T06:12:43Z"
+        );
+        assert_eq!(
+            format_date(Duration::from_secs(951_782_400)),
+            "2000-02-29T00:00:00Z"
+        );
+    }
+
+    #[test]
+    fn finds_latest_run_on_the_same_machine() {
+        let history = get_mock_history();
+        let day_1 = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let day_2 = PuzzleId::new(year!(2025), day!(2)).unwrap();
+
+        let baseline = history.baseline_for(day_1, "laptop", None).unwrap();
+        assert_eq!(baseline.part_1.as_deref(), Some("12ms"));
+
+        let baseline = history.baseline_for(day_2, "laptop", None).unwrap();
+        assert_eq!(baseline.part_1.as_deref(), Some("5ms"));
+
+        assert!(history.baseline_for(day_2, "desktop", None).is_none());
+    }
+
+    #[test]
+    fn finds_chosen_baseline() {
+        let history = get_mock_history();
+        let day_1 = PuzzleId::new(year!(2025), day!(1)).unwrap();
+
+        let baseline = history.baseline_for(day_1, "laptop", Some("aaa")).unwrap();
+        assert_eq!(baseline.part_1.as_deref(), Some("10ms"));
+
+        assert!(history.baseline_for(day_1, "laptop", Some("fff")).is_none());
+    }
+
+    #[test]
+    fn flags_regressions() {
+        let history = get_mock_history();
+        let comparisons = compare(
+            &history,
+            &[timing(1, 15, Some(19)), timing(3, 1, None)],
+            "laptop",
+            None,
+            Statistic::Median,
+        );
+
+        assert_eq!(comparisons.len(), 2);
+        assert_eq!(
+            comparisons[0],
+            Comparison {
+                puzzle: PuzzleId::new(year!(2025), day!(1)).unwrap(),
+                part: 1,
+                before: Duration::from_millis(12),
+                after: Duration::from_millis(15),
+            }
+        );
+        assert!((comparisons[0].change() - 25.0).abs() < 1e-9);
+        assert!(comparisons[0].is_regression(10.0));
+        assert!(!comparisons[0].is_regression(30.0));
+        assert!(!comparisons[1].is_regression(0.0));
+    }
+
+    #[test]
+    fn skips_days_outside_the_event() {
+        let json = r#"{ "runs": [{ "commit": null, "date": "2025-12-01T00:00:00Z", "rustc": null, "machine": "laptop",
+            "timings": [{ "year": "2025", "day": "13", "part_1": "1ms", "part_2": null, "total_nanos": 1 },
+                { "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1 }] }] }"#;
+        let history = History::try_from(json.to_string()).unwrap();
+        assert_eq!(history.runs[0].timings.len(), 1);
+        assert_eq!(history.runs[0].timings[0].day, day!(1));
+    }
+
+    #[test]
+    fn serializes_history() {
+        let history = get_mock_history();
+        let json = tinyjson::JsonValue::from(&history).stringify().unwrap();
+        let parsed = History::try_from(json).unwrap();
+        assert_eq!(parsed.runs.len(), 3);
+        assert_eq!(parsed.runs[2].commit.as_deref(), Some("ccccccc"));
+        assert_eq!(
+            parsed.runs[0].timings[0].stats[1],
+            history.runs[0].timings[0].stats[1]
+        );
+    }
+}
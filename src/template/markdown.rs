@@ -0,0 +1,214 @@
+//! Converts the puzzle descriptions of adventofcode.com from HTML to Markdown.
+//! Only the small set of tags used on puzzle pages is supported, other tags are dropped.
+
+static ARTICLE_START: &str = "<article class=\"day-desc\">";
+static ARTICLE_END: &str = "</article>";
+
+/// Extracts every puzzle description (`<article class="day-desc">`) of a puzzle page as Markdown.
+pub fn puzzle_to_markdown(html: &str) -> String {
+    let mut articles = vec![];
+    let mut rest = html;
+
+    while let Some(start) = rest.find(ARTICLE_START) {
+        let body = &rest[start + ARTICLE_START.len()..];
+        let end = body.find(ARTICLE_END).unwrap_or(body.len());
+        articles.push(to_markdown(&body[..end]));
+        rest = &body[end..];
+    }
+
+    articles.join("\n\n")
+}
+
+/// Converts an HTML fragment to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut writer = Writer::default();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        match rest.find('<') {
+            Some(0) => {
+                let end = rest.find('>').map_or(rest.len(), |i| i + 1);
+                writer.tag(&rest[..end]);
+                rest = &rest[end..];
+            }
+            Some(pos) => {
+                writer.text(&decode_entities(&rest[..pos]));
+                rest = &rest[pos..];
+            }
+            None => {
+                writer.text(&decode_entities(rest));
+                rest = "";
+            }
+        }
+    }
+
+    writer.finish()
+}
+
+#[derive(Default)]
+struct Writer {
+    out: String,
+    in_pre: bool,
+    in_code: bool,
+    links: Vec<String>,
+}
+
+impl Writer {
+    fn tag(&mut self, raw: &str) {
+        let inner = raw.trim_start_matches('<').trim_end_matches('>');
+        let (is_closing, inner) = match inner.strip_prefix('/') {
+            Some(inner) => (true, inner),
+            None => (false, inner),
+        };
+        let name = inner
+            .split(|c: char| c.is_whitespace() || c == '/')
+            .next()
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+
+        match (name.as_str(), is_closing) {
+            ("h2", false) => {
+                self.block();
+                self.out.push_str("## ");
+            }
+            ("p" | "h2" | "ul", _) => self.block(),
+            ("pre", false) => {
+                self.block();
+                self.out.push_str("```\n");
+                self.in_pre = true;
+            }
+            ("pre", true) => {
+                if !self.out.ends_with('\n') {
+                    self.out.push('\n');
+                }
+                self.out.push_str("```");
+                self.in_pre = false;
+                self.block();
+            }
+            ("code", false) if !self.in_pre => {
+                self.out.push('`');
+                self.in_code = true;
+            }
+            ("code", true) if !self.in_pre => {
+                self.out.push('`');
+                self.in_code = false;
+            }
+            ("em", _) if !self.in_pre && !self.in_code => self.out.push('*'),
+            ("li", false) => {
+                self.line();
+                self.out.push_str("- ");
+            }
+            ("li", true) => self.line(),
+            ("br", _) => self.out.push_str("  \n"),
+            ("a", false) => {
+                self.out.push('[');
+                self.links
+                    .push(attribute(inner, "href").unwrap_or_default());
+            }
+            ("a", true) => {
+                let href = self.links.pop().unwrap_or_default();
+                self.out.push_str(&format!("]({href})"));
+            }
+            _ => {}
+        }
+    }
+
+    fn text(&mut self, text: &str) {
+        if self.in_pre {
+            self.out.push_str(text);
+            return;
+        }
+
+        // collapse whitespace like a browser would, outside of preformatted blocks.
+        let mut last_was_space = self.out.is_empty() || self.out.ends_with([' ', '\n']);
+        for ch in text.chars() {
+            if ch.is_whitespace() {
+                if !last_was_space {
+                    self.out.push(' ');
+                }
+                last_was_space = true;
+            } else {
+                self.out.push(ch);
+                last_was_space = false;
+            }
+        }
+    }
+
+    /// Starts a new line unless the output already is at the start of one.
+    fn line(&mut self) {
+        self.trim_trailing_spaces();
+        if !self.out.is_empty() && !self.out.ends_with('\n') {
+            self.out.push('\n');
+        }
+    }
+
+    /// Separates blocks by an empty line.
+    fn block(&mut self) {
+        self.line();
+        if !self.out.is_empty() && !self.out.ends_with("\n\n") {
+            self.out.push('\n');
+        }
+    }
+
+    fn trim_trailing_spaces(&mut self) {
+        let len = self.out.trim_end_matches(' ').len();
+        self.out.truncate(len);
+    }
+
+    fn finish(self) -> String {
+        self.out.trim().to_string()
+    }
+}
+
+/// Reads the value of a double-quoted attribute from the inside of a tag.
+fn attribute(tag: &str, name: &str) -> Option<String> {
+    let needle = format!("{name}=\"");
+    let start = tag.find(&needle)? + needle.len();
+    let end = tag[start..].find('"')? + start;
+    Some(decode_entities(&tag[start..end]))
+}
+
+fn decode_entities(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{puzzle_to_markdown, to_markdown};
+
+    #[test]
+    fn converts_inline_markup() {
+        assert_eq!(
+            to_markdown(
+                "<p>The <em>answer</em> is <code>42</code>, see <a href=\"/2025\">here</a>.</p>"
+            ),
+            "The *answer* is `42`, see [here](/2025)."
+        );
+    }
+
+    #[test]
+    fn converts_blocks() {
+        let html = "<h2>--- Day 1: Test ---</h2><p>First\n  paragraph.</p>\
+            <pre><code>1 &lt; 2\n<em>3</em>\n</code></pre>\
+            <ul>\n<li>one</li>\n<li>two</li>\n</ul><p>End.</p>";
+        assert_eq!(
+            to_markdown(html),
+            "## --- Day 1: Test ---\n\nFirst paragraph.\n\n```\n1 < 2\n3\n```\n\n- one\n- two\n\nEnd."
+        );
+    }
+
+    #[test]
+    fn extracts_puzzle_descriptions() {
+        let html = "<main><article class=\"day-desc\"><h2>Part 1</h2><p>a</p></article>\
+            <p>Your puzzle answer was <code>1</code>.</p>\
+            <article class=\"day-desc\"><h2 id=\"part2\">Part 2</h2><p>b</p></article></main>";
+        assert_eq!(puzzle_to_markdown(html), "## Part 1\n\na\n\n## Part 2\n\nb");
+    }
+}
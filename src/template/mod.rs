@@ -1,15 +1,26 @@
 use std::{env, fs};
 
-pub mod aoc_cli;
+pub mod answers;
+pub mod aoc_client;
 pub mod commands;
+pub mod context;
+pub mod examples;
+pub mod registry;
 pub mod runner;
+pub mod stats;
 
 pub use day::*;
+pub use puzzle::*;
+pub use year::*;
 
 mod day;
+mod history;
+mod markdown;
+mod puzzle;
 mod readme_benchmarks;
 mod run_multi;
 mod timings;
+mod year;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
@@ -17,43 +28,105 @@ pub const ANSI_RESET: &str = "\x1b[0m";
 
 /// Helper function that reads a text file to a string.
 #[must_use]
-pub fn read_file(folder: &str, day: Day) -> String {
+pub fn read_file(folder: &str, puzzle: PuzzleId) -> String {
     let cwd = env::current_dir().unwrap();
-    let filepath = cwd.join("data").join(folder).join(format!("{day}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+    let filepath = cwd.join(puzzle.data_path(folder, "txt"));
+    let f = fs::read_to_string(filepath).expect("could not open input file");
+    strip_example_front_matter(folder, f)
 }
 
 /// Helper function that reads a text file to string, appending a part suffix. E.g. like `01-2.txt`.
 #[must_use]
-pub fn read_file_part(folder: &str, day: Day, part: u8) -> String {
+pub fn read_file_part(folder: &str, puzzle: PuzzleId, part: u8) -> String {
     let cwd = env::current_dir().unwrap();
     let filepath = cwd
         .join("data")
+        .join(puzzle.year().to_string())
         .join(folder)
-        .join(format!("{day}-{part}.txt"));
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+        .join(format!("{}-{part}.txt", puzzle.day()));
+    let f = fs::read_to_string(filepath).expect("could not open input file");
+    strip_example_front_matter(folder, f)
 }
 
-/// Creates the constant `DAY` and sets up the input and runner for each part.
+/// Removes the expected answers from the front matter of example files, see [`crate::template::examples`].
+fn strip_example_front_matter(folder: &str, content: String) -> String {
+    if folder == "examples" {
+        examples::strip_front_matter(&content).to_string()
+    } else {
+        content
+    }
+}
+
+/// Creates the constants `PUZZLE` and `DAY` and sets up the input and runner for each part.
+/// The parts are also exposed as `SOLUTION`, which registers them in the aggregate `solutions` binary.
+///
+/// The year is taken from the file name, i.e. `src/bin/2025_01.rs` is day 1 of 2025.
+/// The optional `parse` parameter declares a `parse(&str) -> Input` function whose result is passed to
+/// both parts as `&Input`, so parsing is timed separately.
+/// Parts may take the [`crate::template::context::Context`] of the input as second argument, e.g.
+/// `part_one(input: &str, context: &Context)`, to read parameters that differ between the example and the real input.
+/// The optional, last parameter (1 or 2) allows you to only run a single part of the solution.
 ///
-/// The optional, second parameter (1 or 2) allows you to only run a single part of the solution.
+/// In tests, every example of the day is checked against the answers in its front matter, see [`crate::template::examples`].
 #[macro_export]
 macro_rules! solution {
     ($day:expr) => {
-        $crate::solution!(@impl $day, [part_one, 1] [part_two, 2]);
+        $crate::solution!(@impl $day, [] [part_one, 1] [part_two, 2]);
     };
     ($day:expr, 1) => {
-        $crate::solution!(@impl $day, [part_one, 1]);
+        $crate::solution!(@impl $day, [] [part_one, 1]);
     };
     ($day:expr, 2) => {
-        $crate::solution!(@impl $day, [part_two, 2]);
+        $crate::solution!(@impl $day, [] [part_two, 2]);
+    };
+    ($day:expr, parse) => {
+        $crate::solution!(@impl $day, [parse] [part_one, 1] [part_two, 2]);
+    };
+    ($day:expr, parse, 1) => {
+        $crate::solution!(@impl $day, [parse] [part_one, 1]);
+    };
+    ($day:expr, parse, 2) => {
+        $crate::solution!(@impl $day, [parse] [part_two, 2]);
     };
 
-    (@impl $day:expr, $( [$func:expr, $part:expr] )*) => {
+    (@impl $day:expr, [$($parse:ident)?] $( [$func:expr, $part:expr] )*) => {
+        /// The current puzzle.
+        const PUZZLE: $crate::template::PuzzleId = $crate::puzzle!($day);
         /// The current day.
-        const DAY: $crate::template::Day = $crate::day!($day);
+        #[allow(dead_code)]
+        const DAY: $crate::template::Day = PUZZLE.day();
+
+        /// The parts of this solution, see [`$crate::template::registry`].
+        #[allow(dead_code)]
+        pub const SOLUTION: $crate::template::registry::Solution =
+            $crate::template::registry::Solution {
+                puzzle: PUZZLE,
+                parts: &[$( $part ),*],
+                has_parse: !stringify!($($parse)?).is_empty(),
+                run: |input: &str,
+                      context: &$crate::template::context::Context,
+                      is_timed: bool,
+                      format: $crate::template::runner::Format| {
+                    use $crate::template::runner::*;
+                    let mut results = vec![];
+                    $(
+                        let (input, result) = execute_parse($parse, input, PUZZLE, is_timed, format);
+                        results.push(result);
+                        let Some(input) = input else {
+                            return results;
+                        };
+                        let input = &input;
+                    )?
+                    $( results.push(execute_part($func, input, context, PUZZLE, $part, is_timed, format)); )*
+                    results
+                },
+            };
+
+        #[cfg(test)]
+        #[test]
+        fn test_examples() {
+            $crate::template::examples::check(&SOLUTION);
+        }
 
         #[cfg(feature = "dhat-heap")]
         #[global_allocator]
@@ -61,8 +134,9 @@ macro_rules! solution {
 
         fn main() {
             use $crate::template::runner::*;
-            let input = $crate::template::read_file("inputs", DAY);
-            $( run_part($func, &input, DAY, $part); )*
+            let (input, context) = read_input(PUZZLE);
+            $( let input = run_parse($parse, &input, PUZZLE); )?
+            $( run_part($func, &input, &context, PUZZLE, $part); )*
         }
     };
 }
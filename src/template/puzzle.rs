@@ -0,0 +1,208 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+#[cfg(feature = "today")]
+use chrono::{Datelike, FixedOffset, Utc};
+
+use crate::template::{Day, Year};
+
+#[cfg(feature = "today")]
+const SERVER_UTC_OFFSET: i32 = -5;
+
+/// Identifies a single puzzle by its year and a day of that year's event.
+///
+/// # Display
+/// This value displays as `<year>_<day>`, which is also the name of the solution binary.
+///
+/// ```
+/// # use advent_of_code::template::{Day, PuzzleId, Year};
+/// let puzzle = PuzzleId::new(Year::new(2025).unwrap(), Day::new(8).unwrap()).unwrap();
+/// assert_eq!(puzzle.to_string(), "2025_08")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PuzzleId {
+    year: Year,
+    day: Day,
+}
+
+impl PuzzleId {
+    /// Creates a [`PuzzleId`] if `day` is part of the event of `year`, returns [`None`] otherwise.
+    pub const fn new(year: Year, day: Day) -> Option<Self> {
+        if !year.contains(day) {
+            return None;
+        }
+        Some(Self { year, day })
+    }
+
+    /// Creates a [`PuzzleId`] from values that were validated before, e.g. when they were stored.
+    pub(crate) const fn new_unchecked(year: Year, day: Day) -> Self {
+        Self { year, day }
+    }
+
+    pub const fn year(self) -> Year {
+        self.year
+    }
+
+    pub const fn day(self) -> Day {
+        self.day
+    }
+
+    /// Number of parts of this puzzle, see [`Year::part_count`].
+    pub const fn part_count(self) -> u8 {
+        self.year.part_count(self.day)
+    }
+
+    /// Path to the solution binary of this puzzle, relative to the project root.
+    #[must_use]
+    pub fn bin_path(self) -> String {
+        format!("./src/bin/{self}.rs")
+    }
+
+    /// Path to a data file of this puzzle, relative to the project root. E.g. `data/2025/inputs/01.txt`.
+    #[must_use]
+    pub fn data_path(self, folder: &str, extension: &str) -> String {
+        format!("data/{}/{folder}/{}.{extension}", self.year, self.day)
+    }
+
+    /// Path to an example of this puzzle, relative to the project root. E.g. `data/2025/examples/01.txt`, or
+    /// `data/2025/examples/01-2.txt` for example 2.
+    #[must_use]
+    pub fn example_path(self, n: Option<u8>) -> String {
+        match n {
+            Some(n) => format!("data/{}/examples/{}-{n}.txt", self.year, self.day),
+            None => self.data_path("examples", "txt"),
+        }
+    }
+}
+
+#[cfg(feature = "today")]
+impl PuzzleId {
+    /// Returns the current puzzle if it's between the 1st of december and the last day of the
+    /// event, `None` otherwise.
+    pub fn today() -> Option<Self> {
+        let offset = FixedOffset::east_opt(SERVER_UTC_OFFSET * 3600)?;
+        let today = Utc::now().with_timezone(&offset);
+        if today.month() == 12 {
+            Self::new(
+                Year::new(u16::try_from(today.year()).ok()?)?,
+                Day::new(u8::try_from(today.day()).ok()?)?,
+            )
+        } else {
+            None
+        }
+    }
+}
+
+impl Display for PuzzleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}_{}", self.year, self.day)
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for PuzzleId {
+    type Err = PuzzleIdFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (year, day) = s.split_once('_').ok_or(PuzzleIdFromStrError)?;
+        Self::new(
+            year.parse().map_err(|_| PuzzleIdFromStrError)?,
+            day.parse().map_err(|_| PuzzleIdFromStrError)?,
+        )
+        .ok_or(PuzzleIdFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`PuzzleId`].
+#[derive(Debug)]
+pub struct PuzzleIdFromStrError;
+
+impl Error for PuzzleIdFromStrError {}
+
+impl Display for PuzzleIdFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a puzzle id of the form <year>_<day>, e.g. 2025_01")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// An iterator that yields every puzzle of the given year's event.
+pub fn all_puzzles(year: Year) -> impl Iterator<Item = PuzzleId> {
+    year.days()
+        .map(move |day| PuzzleId::new_unchecked(year, day))
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates the [`PuzzleId`] of a solution in a const context.
+///
+/// The year is taken from the source file name (`src/bin/<year>_<day>.rs`), the day is passed explicitly.
+#[macro_export]
+macro_rules! puzzle {
+    ($day:expr) => {
+        const {
+            $crate::template::PuzzleId::new(
+                $crate::template::Year::from_source_path(file!()).expect(
+                    "invalid file name, expecting src/bin/<year>_<day>.rs, e.g. 2025_01.rs",
+                ),
+                $crate::day!($day),
+            )
+            .expect("invalid day number, the day is not part of this year's event")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{PuzzleId, all_puzzles};
+    use crate::{day, year};
+
+    #[test]
+    fn displays_as_bin_name() {
+        let puzzle = PuzzleId::new(year!(2024), day!(3)).unwrap();
+        assert_eq!(puzzle.to_string(), "2024_03");
+        assert_eq!(puzzle.bin_path(), "./src/bin/2024_03.rs");
+        assert_eq!(puzzle.data_path("inputs", "txt"), "data/2024/inputs/03.txt");
+        assert_eq!(puzzle.example_path(None), "data/2024/examples/03.txt");
+        assert_eq!(puzzle.example_path(Some(2)), "data/2024/examples/03-2.txt");
+    }
+
+    #[test]
+    fn parses_puzzle_ids() {
+        assert_eq!(
+            "2024_03".parse::<PuzzleId>().ok(),
+            PuzzleId::new(year!(2024), day!(3))
+        );
+        assert!("2024".parse::<PuzzleId>().is_err());
+        assert!("2024_26".parse::<PuzzleId>().is_err());
+        assert!("2025_13".parse::<PuzzleId>().is_err());
+    }
+
+    #[test]
+    fn rejects_days_outside_of_the_event() {
+        assert!(PuzzleId::new(year!(2024), day!(25)).is_some());
+        assert!(PuzzleId::new(year!(2025), day!(12)).is_some());
+        assert!(PuzzleId::new(year!(2025), day!(13)).is_none());
+    }
+
+    #[test]
+    fn orders_by_year_first() {
+        assert!(PuzzleId::new(year!(2024), day!(25)) < PuzzleId::new(year!(2025), day!(1)));
+    }
+
+    #[test]
+    fn iterates_puzzles_of_a_year() {
+        let mut iter = all_puzzles(year!(2023));
+        assert_eq!(iter.next(), PuzzleId::new(year!(2023), day!(1)));
+        assert_eq!(iter.last(), PuzzleId::new(year!(2023), day!(25)));
+
+        let mut iter = all_puzzles(year!(2025));
+        assert_eq!(iter.next(), PuzzleId::new(year!(2025), day!(1)));
+        assert_eq!(iter.last(), PuzzleId::new(year!(2025), day!(12)));
+    }
+}
@@ -2,7 +2,8 @@
 /// The approach taken is similar to how `aoc-readme-stars` handles this.
 use std::{fs, io};
 
-use crate::template::Day;
+use crate::template::PuzzleId;
+use crate::template::stats::Statistic;
 use crate::template::timings::Timings;
 
 static MARKER: &str = "<!--- benchmarking table --->";
@@ -26,8 +27,8 @@ pub struct TablePosition {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    puzzle.bin_path()
 }
 
 fn locate_table(readme: &str) -> Result<TablePosition, Error> {
@@ -52,26 +53,55 @@ fn locate_table(readme: &str) -> Result<TablePosition, Error> {
     Ok(TablePosition { pos_start, pos_end })
 }
 
-fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String {
+fn construct_table(
+    prefix: &str,
+    timings: Timings,
+    total_millis: f64,
+    statistic: Statistic,
+) -> String {
     let header = format!("{prefix} Benchmarks");
 
-    let mut lines: Vec<String> = vec![
-        MARKER.into(),
-        header,
-        String::new(),
-        "| Day | Part 1 | Part 2 |".into(),
-        "| :---: | :---: | :---:  |".into(),
-    ];
+    // only add a parse column once a solution parses its input separately.
+    let has_parse = timings.data.iter().any(|timing| timing.parse.is_some());
+
+    let mut lines: Vec<String> = vec![MARKER.into(), header, String::new()];
+    if has_parse {
+        lines.push("| Day | Parse | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---: | :---:  |".into());
+    } else {
+        lines.push("| Day | Part 1 | Part 2 |".into());
+        lines.push("| :---: | :---: | :---:  |".into());
+    }
+
+    // only prefix days with their year once timings of several years share the table.
+    let is_multi_year = timings
+        .data
+        .windows(2)
+        .any(|pair| pair[0].year != pair[1].year);
 
     for timing in timings.data {
-        let path = get_path_for_bin(timing.day);
-        lines.push(format!(
-            "| [Day {}]({}) | `{}` | `{}` |",
-            timing.day.into_inner(),
-            path,
-            timing.part_1.unwrap_or_else(|| "-".into()),
-            timing.part_2.unwrap_or_else(|| "-".into())
-        ));
+        let path = get_path_for_bin(timing.puzzle());
+        let label = if is_multi_year {
+            format!("{} Day {}", timing.year, timing.day.into_inner())
+        } else {
+            format!("Day {}", timing.day.into_inner())
+        };
+        let cell = |part: u8| {
+            format!(
+                " `{}` |",
+                timing
+                    .format_part(part, statistic)
+                    .unwrap_or_else(|| "-".into())
+            )
+        };
+
+        let mut line = format!("| [{label}]({path}) |");
+        if has_parse {
+            line.push_str(&cell(0));
+        }
+        line.push_str(&cell(1));
+        line.push_str(&cell(2));
+        lines.push(line);
     }
 
     lines.push(String::new());
@@ -81,47 +111,65 @@ fn construct_table(prefix: &str, timings: Timings, total_millis: f64) -> String
     lines.join("\n")
 }
 
-fn update_content(s: &mut String, timings: Timings, total_millis: f64) -> Result<(), Error> {
+fn update_content(
+    s: &mut String,
+    timings: Timings,
+    total_millis: f64,
+    statistic: Statistic,
+) -> Result<(), Error> {
     let positions = locate_table(s)?;
-    let table = construct_table("##", timings, total_millis);
+    let table = construct_table("##", timings, total_millis, statistic);
     s.replace_range(positions.pos_start..positions.pos_end, &table);
     Ok(())
 }
 
-pub fn update(timings: Timings) -> Result<(), Error> {
+/// Updates the README table, showing `statistic` for timings that have benchmark statistics.
+pub fn update(timings: Timings, statistic: Statistic) -> Result<(), Error> {
     let path = "README.md";
     let mut readme = String::from_utf8_lossy(&fs::read(path)?).to_string();
-    let total_millis = timings.total_millis();
-    update_content(&mut readme, timings, total_millis)?;
+    let total_millis = timings.total_millis_of(statistic);
+    update_content(&mut readme, timings, total_millis, statistic)?;
     fs::write(path, &readme)?;
     Ok(())
 }
 
 #[cfg(feature = "test_lib")]
 mod tests {
+    use std::time::Duration;
+
     use super::{MARKER, update_content};
-    use crate::{day, template::timings::Timing, template::timings::Timings};
+    use crate::template::stats::{Statistic, Stats};
+    use crate::{day, template::timings::Timing, template::timings::Timings, year};
 
     fn get_mock_timings() -> Timings {
         Timings {
             data: vec![
                 Timing {
+                    year: year!(2025),
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    stats: [None, None, None],
                 },
                 Timing {
+                    year: year!(2025),
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    stats: [None, None, None],
                 },
                 Timing {
+                    year: year!(2025),
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: Some("50ms".into()),
                     total_nanos: 9e+10,
+                    stats: [None, None, None],
                 },
             ],
         }
@@ -131,28 +179,28 @@ mod tests {
     #[should_panic]
     fn errors_if_marker_not_present() {
         let mut s = "# readme".to_string();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Median).unwrap();
     }
 
     #[test]
     #[should_panic]
     fn errors_if_too_many_markers_present() {
         let mut s = format!("{} {} {}", MARKER, MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Median).unwrap();
     }
 
     #[test]
     fn updates_empty_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Median).unwrap();
         assert_eq!(s.contains("## Benchmarks"), true);
     }
 
     #[test]
     fn updates_existing_benchmarks() {
         let mut s = format!("foo\nbar\n{}{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Median).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Median).unwrap();
         assert_eq!(s.matches(MARKER).collect::<Vec<&str>>().len(), 2);
         assert_eq!(s.matches("## Benchmarks").collect::<Vec<&str>>().len(), 1);
     }
@@ -160,7 +208,7 @@ mod tests {
     #[test]
     fn format_benchmarks() {
         let mut s = format!("foo\nbar\n{}\n{}\nbaz", MARKER, MARKER);
-        update_content(&mut s, get_mock_timings(), 190.0).unwrap();
+        update_content(&mut s, get_mock_timings(), 190.0, Statistic::Median).unwrap();
         let expected = [
             "foo",
             "bar",
@@ -169,9 +217,9 @@ mod tests {
             "",
             "| Day | Part 1 | Part 2 |",
             "| :---: | :---: | :---:  |",
-            "| [Day 1](./src/bin/01.rs) | `10ms` | `20ms` |",
-            "| [Day 2](./src/bin/02.rs) | `30ms` | `40ms` |",
-            "| [Day 4](./src/bin/04.rs) | `40ms` | `50ms` |",
+            "| [Day 1](./src/bin/2025_01.rs) | `10ms` | `20ms` |",
+            "| [Day 2](./src/bin/2025_02.rs) | `30ms` | `40ms` |",
+            "| [Day 4](./src/bin/2025_04.rs) | `40ms` | `50ms` |",
             "",
             "**Total: 190.00ms**",
             "<!--- benchmarking table --->",
@@ -180,4 +228,50 @@ mod tests {
         .join("\n");
         assert_eq!(s, expected);
     }
+
+    #[test]
+    fn prefixes_days_with_years_when_mixed() {
+        let mut timings = get_mock_timings();
+        timings.data[0].year = year!(2024);
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, Statistic::Median).unwrap();
+        assert!(s.contains("| [2024 Day 1](./src/bin/2024_01.rs) | `10ms` | `20ms` |"));
+        assert!(s.contains("| [2025 Day 2](./src/bin/2025_02.rs) | `30ms` | `40ms` |"));
+    }
+
+    #[test]
+    fn formats_selected_statistic() {
+        let mut timings = get_mock_timings();
+        let stats = Stats::from_samples(&[
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+            Duration::from_millis(9),
+        ])
+        .unwrap();
+        timings.data[0].stats = [None, Some(stats), None];
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings.clone(), 190.0, Statistic::Median).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/2025_01.rs) | `2.0ms` | `20ms` |"));
+
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings.clone(), 190.0, Statistic::Max).unwrap();
+        assert!(s.contains("| [Day 1](./src/bin/2025_01.rs) | `9.0ms` | `20ms` |"));
+
+        assert_eq!(
+            timings.total_millis_of(Statistic::Median),
+            2.0 + 70_000.0 + 90_000.0
+        );
+    }
+
+    #[test]
+    fn adds_parse_column() {
+        let mut timings = get_mock_timings();
+        timings.data[1].parse = Some("5ms".into());
+        let mut s = format!("{}\n{}", MARKER, MARKER);
+        update_content(&mut s, timings, 190.0, Statistic::Median).unwrap();
+        assert!(s.contains("| Day | Parse | Part 1 | Part 2 |"));
+        assert!(s.contains("| [Day 1](./src/bin/2025_01.rs) | `-` | `10ms` | `20ms` |"));
+        assert!(s.contains("| [Day 2](./src/bin/2025_02.rs) | `5ms` | `30ms` | `40ms` |"));
+    }
 }
@@ -0,0 +1,141 @@
+//! Solutions registered by the `solution!` macro.
+//!
+//! `build.rs` includes every solution into the aggregate `solutions` binary, which runs them
+//! in-process. The `all`, `time` and `verify` commands invoke it and read its JSON output.
+
+use std::collections::HashMap;
+use std::{fs, process};
+
+use tinyjson::JsonValue;
+
+use crate::template::PuzzleId;
+use crate::template::context::Context;
+use crate::template::runner::{Format, PartResult, print_part};
+
+/// Runs a solution on an input with its parameters, printing the results in the given format. Takes `is_timed` to
+/// bench the solution.
+pub type RunFn = fn(&str, &Context, bool, Format) -> Vec<PartResult>;
+
+/// The parts of one puzzle's solution.
+#[derive(Clone, Copy)]
+pub struct Solution {
+    pub puzzle: PuzzleId,
+    /// The parts this solution runs, not including the parse step.
+    pub parts: &'static [u8],
+    /// Whether the solution has a parse step, reported as part [`crate::template::runner::PARSE_PART`].
+    pub has_parse: bool,
+    /// Runs the parse step, if any, and every part.
+    pub run: RunFn,
+}
+
+impl Solution {
+    /// Runs the solution on `input`, printing the results in `format`.
+    pub fn run(
+        &self,
+        input: &str,
+        context: &Context,
+        is_timed: bool,
+        format: Format,
+    ) -> Vec<PartResult> {
+        (self.run)(input, context, is_timed, format)
+    }
+}
+
+/// JSON line printed before running `puzzle`, so a reader can tell which puzzle is running and whether a parse step
+/// comes before its parts.
+#[must_use]
+pub fn started_event(puzzle: PuzzleId, has_parse: bool) -> String {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert("event".into(), JsonValue::String("started".into()));
+    map.insert(
+        "year".into(),
+        JsonValue::Number(f64::from(puzzle.year().into_inner())),
+    );
+    map.insert(
+        "day".into(),
+        JsonValue::Number(f64::from(puzzle.day().into_inner())),
+    );
+    map.insert("parse".into(), JsonValue::Boolean(has_parse));
+    JsonValue::Object(map).stringify().unwrap()
+}
+
+/// JSON line printed once the first run of `part` (0 for the parse step) of `puzzle` returned, before it is benched.
+#[must_use]
+pub fn answered_event(puzzle: PuzzleId, part: u8) -> String {
+    let mut map: HashMap<String, JsonValue> = HashMap::new();
+    map.insert("event".into(), JsonValue::String("answered".into()));
+    map.insert(
+        "year".into(),
+        JsonValue::Number(f64::from(puzzle.year().into_inner())),
+    );
+    map.insert(
+        "day".into(),
+        JsonValue::Number(f64::from(puzzle.day().into_inner())),
+    );
+    map.insert("part".into(), JsonValue::Number(f64::from(part)));
+    JsonValue::Object(map).stringify().unwrap()
+}
+
+/// Looks up the solution of `puzzle`.
+#[must_use]
+pub fn find(solutions: &[Solution], puzzle: PuzzleId) -> Option<&Solution> {
+    solutions.iter().find(|solution| solution.puzzle == puzzle)
+}
+
+/// Entry point of the aggregate `solutions` binary.
+///
+/// Runs the puzzles passed as arguments (e.g. `2025_01`) in order, skipping puzzles without a solution.
+/// Accepts `--time` to bench the solutions and `--format json` to print one JSON object per part.
+pub fn main(solutions: &[Solution]) {
+    let mut args = pico_args::Arguments::from_env();
+    let is_timed = args.contains("--time");
+    let format = args
+        .opt_value_from_str("--format")
+        .map(Option::unwrap_or_default);
+
+    let puzzles: Result<Vec<PuzzleId>, String> = args
+        .finish()
+        .iter()
+        .map(|arg| {
+            arg.to_string_lossy()
+                .parse::<PuzzleId>()
+                .map_err(|err| err.to_string())
+        })
+        .collect();
+
+    let (format, puzzles) = match (format, puzzles) {
+        (Ok(format), Ok(puzzles)) => (format, puzzles),
+        (Err(err), _) => exit_with(&err.to_string()),
+        (_, Err(err)) => exit_with(&err),
+    };
+
+    for puzzle in puzzles {
+        let Some(solution) = find(solutions, puzzle) else {
+            continue;
+        };
+
+        if format == Format::Json {
+            println!("{}", started_event(puzzle, solution.has_parse));
+        }
+
+        match fs::read_to_string(puzzle.data_path("inputs", "txt")) {
+            Ok(input) => {
+                solution.run(&input, &Context::for_input(puzzle), is_timed, format);
+            }
+            Err(_) => {
+                for part in solution.parts {
+                    let message = format!(
+                        "no input, download it with `cargo download {}`",
+                        puzzle.day()
+                    );
+                    print_part(&PartResult::error(puzzle, *part, message), format);
+                }
+            }
+        }
+    }
+}
+
+fn exit_with(message: &str) -> ! {
+    eprintln!("Error: {message}");
+    process::exit(1);
+}
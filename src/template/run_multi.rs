@@ -1,38 +1,136 @@
-use std::{collections::HashSet, io};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Display;
+use std::path::Path;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::template::runner::{Format, PARSE_PART, PartResult, Status, Timeouts, print_part};
+use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, PuzzleId};
+
+use super::timings::{TIMEOUT, Timing, Timings};
+use child_commands::Event;
+
+/// Progress of [`run_days`].
+enum Progress<'a> {
+    /// The puzzle is next, its results follow.
+    Day(PuzzleId),
+    Part(&'a PartResult),
+    /// All results of the puzzle were reported.
+    DayDone(PuzzleId, Vec<PartResult>),
+}
 
-use crate::template::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET, Day};
+/// Runs `puzzles_to_run` and prints their results in day order.
+///
+/// With more than one job, days are spread over that many `solutions` processes. Their output is buffered and
+/// printed once a day and all days before it are done.
+pub fn run_multi(
+    puzzles_to_run: &HashSet<PuzzleId>,
+    is_release: bool,
+    is_timed: bool,
+    timeouts: Timeouts,
+    jobs: usize,
+) -> Result<Option<Timings>, Error> {
+    let mut timings: Vec<Timing> = Vec::with_capacity(puzzles_to_run.len());
 
-use super::{
-    all_days,
-    timings::{Timing, Timings},
-};
+    let mut need_space = false;
 
-pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -> Option<Timings> {
-    let mut timings: Vec<Timing> = Vec::with_capacity(days_to_run.len());
+    // NOTE: use non-duplicate, sorted puzzle values.
+    let mut puzzles: Vec<PuzzleId> = puzzles_to_run.iter().copied().collect();
+    puzzles.sort_unstable();
 
-    let mut need_space = false;
+    // every worker starts the same executable, parallel builds of it would race.
+    let executable = child_commands::build_solutions(is_release)?;
 
-    // NOTE: use non-duplicate, sorted day values.
-    all_days()
-        .filter(|day| days_to_run.contains(day))
-        .for_each(|day| {
+    let mut print_progress = |progress: Progress| match progress {
+        Progress::Day(puzzle) => {
             if need_space {
                 println!();
             }
             need_space = true;
 
-            println!("{ANSI_BOLD}Day {day}{ANSI_RESET}");
-            println!("------");
-
-            let output = child_commands::run_solution(day, is_timed, is_release).unwrap();
-
-            if output.is_empty() {
+            println!(
+                "{ANSI_BOLD}{} Day {}{ANSI_RESET}",
+                puzzle.year(),
+                puzzle.day()
+            );
+            println!("-----------");
+        }
+        Progress::Part(result) => print_part(result, Format::Human),
+        Progress::DayDone(puzzle, parts) => {
+            if parts.is_empty() {
                 println!("Not solved.");
             } else {
-                let val = child_commands::parse_exec_time(&output, day);
-                timings.push(val);
+                if parts
+                    .iter()
+                    .any(|part| matches!(part.status, Status::Error(_)))
+                {
+                    println!("{ANSI_BOLD}Failed.{ANSI_RESET}");
+                }
+                timings.push(to_timing(puzzle, &parts));
+            }
+        }
+    };
+
+    if jobs <= 1 {
+        run_days(
+            &executable,
+            &puzzles,
+            is_timed,
+            timeouts,
+            &mut print_progress,
+        )?;
+    } else {
+        let (sender, receiver) = mpsc::channel();
+
+        thread::scope(|scope| {
+            // days are dealt out in turn, as later days tend to take longer.
+            let workers: Vec<_> = (0..jobs.min(puzzles.len()))
+                .map(|job| {
+                    let job_puzzles: Vec<PuzzleId> =
+                        puzzles.iter().copied().skip(job).step_by(jobs).collect();
+                    let sender = sender.clone();
+                    let executable = &executable;
+
+                    scope.spawn(move || {
+                        run_days(
+                            executable,
+                            &job_puzzles,
+                            is_timed,
+                            timeouts,
+                            &mut |progress| {
+                                if let Progress::DayDone(puzzle, parts) = progress {
+                                    let _ = sender.send((puzzle, parts));
+                                }
+                            },
+                        )
+                    })
+                })
+                .collect();
+            drop(sender);
+
+            let mut done = BTreeMap::new();
+            let mut next = puzzles.iter().copied().peekable();
+
+            for (puzzle, parts) in receiver {
+                done.insert(puzzle, parts);
+
+                while let Some(parts) = next.peek().and_then(|puzzle| done.remove(puzzle)) {
+                    let puzzle = next.next().unwrap();
+                    print_progress(Progress::Day(puzzle));
+                    for part in &parts {
+                        print_progress(Progress::Part(part));
+                    }
+                    print_progress(Progress::DayDone(puzzle, parts));
+                }
             }
-        });
+
+            // days of a worker that failed are never reported, so the first error is returned once all are done.
+            workers
+                .into_iter()
+                .try_for_each(|worker| worker.join().unwrap())
+        })?;
+    }
 
     if is_timed {
         let timings = Timings { data: timings };
@@ -40,17 +138,188 @@ pub fn run_multi(days_to_run: &HashSet<Day>, is_release: bool, is_timed: bool) -
         println!(
             "\n{ANSI_BOLD}Total (Run):{ANSI_RESET} {ANSI_ITALIC}{total_millis:.2}ms{ANSI_RESET}"
         );
-        Some(timings)
+        Ok(Some(timings))
     } else {
-        None
+        Ok(None)
     }
 }
 
+/// Runs `puzzles` in order without printing anything, calling `on_day` with the results of each day once it is done.
+///
+/// Days that run into a timeout are stopped like in [`run_multi`].
+pub fn run_each(
+    puzzles: &[PuzzleId],
+    is_release: bool,
+    timeouts: Timeouts,
+    mut on_day: impl FnMut(PuzzleId, Vec<PartResult>),
+) -> Result<(), Error> {
+    let executable = child_commands::build_solutions(is_release)?;
+    run_days(&executable, puzzles, false, timeouts, &mut |progress| {
+        if let Progress::DayDone(puzzle, parts) = progress {
+            on_day(puzzle, parts);
+        }
+    })
+}
+
+/// Runs `puzzles` in order in a process of the `solutions` `executable`, reporting each day and result to
+/// `on_progress`.
+///
+/// A puzzle that runs into a timeout is stopped, its remaining steps are reported as timed out and a new process is
+/// started for the puzzles after it.
+fn run_days(
+    executable: &Path,
+    puzzles: &[PuzzleId],
+    is_timed: bool,
+    timeouts: Timeouts,
+    on_progress: &mut impl FnMut(Progress),
+) -> Result<(), Error> {
+    let mut results = child_commands::spawn_solutions(executable, puzzles, is_timed)?;
+    // the first event of the next puzzle, read while waiting for the current one to finish.
+    let mut next_event = None;
+
+    for (i, puzzle) in puzzles.iter().copied().enumerate() {
+        on_progress(Progress::Day(puzzle));
+
+        let mut parts = vec![];
+        let mut has_parse = false;
+        let mut started_at = None;
+        let mut last_event_at = Instant::now();
+        // the part limit only applies until the running part answered, not while it is benched.
+        let mut is_benching = false;
+
+        loop {
+            let event = match next_event.take() {
+                Some(event) => Ok(event),
+                None => results.next_event(started_at.and_then(|started_at| {
+                    timeouts.remaining(started_at, (!is_benching).then_some(last_event_at))
+                })),
+            };
+
+            match event {
+                Ok(Event::Started {
+                    puzzle: started,
+                    has_parse: started_has_parse,
+                }) if started == puzzle => {
+                    has_parse = started_has_parse;
+                    started_at = Some(Instant::now());
+                    last_event_at = Instant::now();
+                }
+                Ok(Event::Answered { puzzle: answered }) if answered == puzzle => {
+                    is_benching = true;
+                }
+                Ok(Event::Result(result)) if result.puzzle == puzzle => {
+                    on_progress(Progress::Part(&result));
+                    parts.push(result);
+                    last_event_at = Instant::now();
+                    is_benching = false;
+                }
+                Ok(event) => {
+                    next_event = Some(event);
+                    break;
+                }
+                Err(RecvTimeoutError::Disconnected) => {
+                    // a solution that ends the process, e.g. by overflowing its stack, takes the puzzles after it along.
+                    if let Some(status) = results.failure() {
+                        let steps = has_parse.then_some(PARSE_PART).into_iter();
+                        if let Some(part) = steps
+                            .chain(1..=puzzle.part_count())
+                            .find(|part| parts.iter().all(|result| result.part != *part))
+                        {
+                            let message = format!("the solution exited unexpectedly ({status})");
+                            let result = PartResult::error(puzzle, part, message);
+                            on_progress(Progress::Part(&result));
+                            parts.push(result);
+                        }
+
+                        results = child_commands::spawn_solutions(
+                            executable,
+                            &puzzles[i + 1..],
+                            is_timed,
+                        )?;
+                    }
+                    break;
+                }
+                Err(RecvTimeoutError::Timeout) => {
+                    results.kill();
+
+                    // the running step gets the time it ran, the steps after it did not run at all.
+                    let mut duration = last_event_at.elapsed();
+                    let steps = has_parse.then_some(PARSE_PART).into_iter();
+                    for part in steps.chain(1..=puzzle.part_count()) {
+                        if parts.iter().all(|result| result.part != part) {
+                            let result = PartResult::timeout(puzzle, part, duration);
+                            on_progress(Progress::Part(&result));
+                            parts.push(result);
+                            duration = Duration::ZERO;
+                        }
+                    }
+
+                    results =
+                        child_commands::spawn_solutions(executable, &puzzles[i + 1..], is_timed)?;
+                    break;
+                }
+            }
+        }
+
+        on_progress(Progress::DayDone(puzzle, parts));
+    }
+
+    Ok(())
+}
+
+/// Collects the timings of the parse step and of the parts that produced an answer or timed out.
+fn to_timing(puzzle: PuzzleId, results: &[PartResult]) -> Timing {
+    let mut timing = Timing {
+        year: puzzle.year(),
+        day: puzzle.day(),
+        parse: None,
+        part_1: None,
+        part_2: None,
+        total_nanos: 0_f64,
+        stats: [None, None, None],
+    };
+
+    for result in results.iter().filter(|result| {
+        result.answer.is_some() || result.part == PARSE_PART || result.status == Status::Timeout
+    }) {
+        let timing_str = Some(if result.status == Status::Timeout {
+            TIMEOUT.into()
+        } else {
+            format!("{:.1?}", result.duration)
+        });
+        match result.part {
+            PARSE_PART => timing.parse = timing_str,
+            1 => timing.part_1 = timing_str,
+            _ => timing.part_2 = timing_str,
+        }
+        if result.status == Status::Timeout {
+            continue;
+        }
+        timing.stats[usize::from(result.part.min(2))] = Some(result.stats);
+        #[allow(clippy::cast_precision_loss)]
+        let nanos = result.duration.as_nanos() as f64;
+        timing.total_nanos += nanos;
+    }
+
+    timing
+}
+
 #[allow(dead_code)]
 #[derive(Debug)]
 pub enum Error {
     BrokenPipe,
-    IO(io::Error),
+    Build(String),
+    IO(std::io::Error),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::BrokenPipe => write!(f, "could not read the output of the solutions"),
+            Error::Build(message) => write!(f, "{message}"),
+            Error::IO(e) => write!(f, "{e}"),
+        }
+    }
 }
 
 impl From<std::io::Error> for Error {
@@ -60,198 +329,350 @@ impl From<std::io::Error> for Error {
 }
 
 #[must_use]
-pub fn get_path_for_bin(day: Day) -> String {
-    format!("./src/bin/{day}.rs")
+pub fn get_path_for_bin(puzzle: PuzzleId) -> String {
+    puzzle.bin_path()
 }
 
-/// All solutions live in isolated binaries.
-/// This module encapsulates interaction with these binaries, both invoking them as well as parsing the timing output.
+/// All solutions are compiled into the aggregate `solutions` binary.
+/// This module encapsulates invoking it and reading its JSON output.
 pub mod child_commands {
     use super::{Error, get_path_for_bin};
-    use crate::template::Day;
+    use crate::template::runner::{InputSource, PartResult};
+    use crate::template::{Day, PuzzleId, Year};
     use std::{
+        collections::HashMap,
         io::{BufRead, BufReader},
-        path::Path,
-        process::{Command, Stdio},
+        path::{Path, PathBuf},
+        process::{Child, Command, ExitStatus, Stdio},
+        str::FromStr,
+        sync::mpsc::{self, Receiver, RecvTimeoutError},
         thread,
+        time::Duration,
     };
+    use tinyjson::JsonValue;
+
+    /// A line of `--format json` output.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub enum Event {
+        /// The `solutions` binary started to run a puzzle.
+        Started { puzzle: PuzzleId, has_parse: bool },
+        /// The first run of a part, or of the parse step, returned and it is benched now.
+        Answered { puzzle: PuzzleId },
+        /// A part, or the parse step, finished.
+        Result(PartResult),
+    }
+
+    /// Results of a running solution, yielded as each part finishes.
+    pub struct Results {
+        child: Child,
+        events: Receiver<Event>,
+        /// Set once all events were read and the process exited.
+        status: Option<ExitStatus>,
+    }
+
+    impl Results {
+        fn new(mut child: Child) -> Result<Self, Error> {
+            let stdout = child.stdout.take().ok_or(Error::BrokenPipe)?;
+            let (sender, events) = mpsc::channel();
+
+            // lines are read on a separate thread, so waiting for them can time out.
+            thread::spawn(move || {
+                for line in BufReader::new(stdout).lines() {
+                    let Ok(line) = line else { break };
+                    match parse_event(&line) {
+                        Ok(event) => {
+                            if sender.send(event).is_err() {
+                                break;
+                            }
+                        }
+                        Err(e) => eprintln!("Could not parse solution output: {e}"),
+                    }
+                }
+            });
 
-    /// Run the solution bin for a given day
-    pub fn run_solution(day: Day, is_timed: bool, is_release: bool) -> Result<Vec<String>, Error> {
-        // skip command invocation for days that have not been scaffolded yet.
-        if !Path::new(&get_path_for_bin(day)).exists() {
-            return Ok(vec![]);
+            Ok(Self {
+                child,
+                events,
+                status: None,
+            })
         }
 
-        let day_padded = day.to_string();
-        let mut args = vec!["run", "--quiet", "--bin", &day_padded];
+        /// Waits for the next event, at most for `timeout` if given.
+        pub fn next_event(&mut self, timeout: Option<Duration>) -> Result<Event, RecvTimeoutError> {
+            let event = match timeout {
+                Some(timeout) => self.events.recv_timeout(timeout),
+                None => self
+                    .events
+                    .recv()
+                    .map_err(|_| RecvTimeoutError::Disconnected),
+            };
+
+            if event == Err(RecvTimeoutError::Disconnected) {
+                self.status = self.child.wait().ok();
+            }
+            event
+        }
 
-        if is_release {
-            args.push("--release");
+        /// The exit status of the solution if it exited unsuccessfully, known once all events were read.
+        pub fn failure(&self) -> Option<ExitStatus> {
+            self.status.filter(|status| !status.success())
+        }
+
+        /// Stops the solution.
+        pub fn kill(&mut self) {
+            let _ = self.child.kill();
+            let _ = self.child.wait();
+        }
+    }
+
+    impl Iterator for Results {
+        type Item = PartResult;
+
+        fn next(&mut self) -> Option<Self::Item> {
+            loop {
+                match self.next_event(None) {
+                    Ok(Event::Result(result)) => return Some(result),
+                    Ok(Event::Started { .. } | Event::Answered { .. }) => {}
+                    Err(_) => return None,
+                }
+            }
         }
+    }
+
+    /// Run the solutions of the given puzzles in a single process of an executable returned by [`build_solutions`].
+    /// Puzzles that have not been scaffolded yet are skipped.
+    pub fn spawn_solutions(
+        executable: &Path,
+        puzzles: &[PuzzleId],
+        is_timed: bool,
+    ) -> Result<Results, Error> {
+        let puzzle_args: Vec<String> = puzzles
+            .iter()
+            .filter(|puzzle| Path::new(&get_path_for_bin(**puzzle)).exists())
+            .map(ToString::to_string)
+            .collect();
+
+        let mut args = vec!["--format", "json"];
 
         if is_timed {
-            // mirror `--time` flag to child invocations.
-            args.push("--");
             args.push("--time");
         }
 
-        // spawn child command with piped stdout/stderr.
-        // forward output to stdout/stderr while grabbing stdout lines.
+        args.extend(puzzle_args.iter().map(String::as_str));
 
-        let mut cmd = Command::new("cargo")
+        // the binary is started directly instead of through `cargo run`, so killing it stops the solution.
+        let child = Command::new(executable)
             .args(&args)
             .stdout(Stdio::piped())
-            .stderr(Stdio::piped())
+            .stderr(Stdio::inherit())
             .spawn()?;
 
-        let stdout = BufReader::new(cmd.stdout.take().ok_or(super::Error::BrokenPipe)?);
-        let stderr = BufReader::new(cmd.stderr.take().ok_or(super::Error::BrokenPipe)?);
-
-        let mut output = vec![];
+        Results::new(child)
+    }
 
-        let thread = thread::spawn(move || {
-            stderr.lines().for_each(|line| {
-                eprintln!("{}", line.unwrap());
-            });
-        });
+    /// Builds the `solutions` binary and returns the path of its executable.
+    pub fn build_solutions(is_release: bool) -> Result<PathBuf, Error> {
+        let mut args = vec![
+            "build",
+            "--quiet",
+            "--bin",
+            "solutions",
+            "--message-format",
+            "json-render-diagnostics",
+        ];
 
-        for line in stdout.lines() {
-            let line = line.unwrap();
-            println!("{line}");
-            output.push(line);
+        if is_release {
+            args.push("--release");
         }
 
-        thread.join().unwrap();
-        cmd.wait()?;
-
-        Ok(output)
-    }
+        // build errors are forwarded to stderr, the executable is read from the build messages on stdout.
+        let output = Command::new("cargo")
+            .args(&args)
+            .stderr(Stdio::inherit())
+            .output()?;
 
-    pub fn parse_exec_time(output: &[String], day: Day) -> super::Timing {
-        let mut timings = super::Timing {
-            day,
-            part_1: None,
-            part_2: None,
-            total_nanos: 0_f64,
-        };
+        if !output.status.success() {
+            return Err(Error::Build("could not build the solutions".into()));
+        }
 
-        output
-            .iter()
-            .filter_map(|l| {
-                if !l.contains(" samples)") {
+        String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .filter_map(|line| JsonValue::from_str(line).ok())
+            .find_map(|message| {
+                let message = message.get::<HashMap<String, JsonValue>>()?;
+                let target = message.get("target")?.get::<HashMap<String, JsonValue>>()?;
+                if target.get("name")?.get::<String>()? != "solutions" {
                     return None;
                 }
+                message
+                    .get("executable")?
+                    .get::<String>()
+                    .map(PathBuf::from)
+            })
+            .ok_or(Error::Build(
+                "could not find the solutions executable".into(),
+            ))
+    }
 
-                let Some((timing_str, nanos)) = parse_time(l) else {
-                    eprintln!("Could not parse timings from line: {l}");
-                    return None;
-                };
+    /// Run the solution of `puzzle` on `input` in its own binary.
+    pub fn run_solution(
+        puzzle: PuzzleId,
+        input: &InputSource,
+        is_release: bool,
+    ) -> Result<Results, Error> {
+        let bin = puzzle.to_string();
+        let input_args = input.to_args();
 
-                let part = l.split(':').next()?;
-                Some((part, timing_str, nanos))
-            })
-            .for_each(|(part, timing_str, nanos)| {
-                if part.contains("Part 1") {
-                    timings.part_1 = Some(timing_str.into());
-                } else if part.contains("Part 2") {
-                    timings.part_2 = Some(timing_str.into());
-                }
+        let mut args = vec!["run", "--quiet", "--bin", &bin];
 
-                timings.total_nanos += nanos;
-            });
+        if is_release {
+            args.push("--release");
+        }
+
+        args.extend(["--", "--format", "json"]);
+        args.extend(input_args.iter().map(String::as_str));
 
-        timings
+        // build errors and panics are forwarded to stderr, results are read from stdout.
+        let child = Command::new("cargo")
+            .args(args)
+            .stdout(Stdio::piped())
+            .stderr(Stdio::inherit())
+            .spawn()?;
+
+        Results::new(child)
     }
 
-    fn parse_to_float(s: &str, postfix: &str) -> Option<f64> {
-        s.split(postfix).next()?.parse().ok()
+    /// Parses a line of `--format json` output.
+    pub fn parse_event(line: &str) -> Result<Event, String> {
+        let json = JsonValue::from_str(line).map_err(|e| e.to_string())?;
+
+        let Some(map) = json
+            .get::<HashMap<String, JsonValue>>()
+            .filter(|map| map.contains_key("event"))
+        else {
+            return PartResult::try_from(&json).map(Event::Result);
+        };
+
+        let number = |key: &str| {
+            map.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected event.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let puzzle = Year::new(number("year")? as u16)
+            .zip(Day::new(number("day")? as u8))
+            .and_then(|(year, day)| PuzzleId::new(year, day))
+            .ok_or("Expected event.year and event.day to be a puzzle.")?;
+
+        // output of solutions built before parse steps were announced has no `parse` key.
+        let has_parse = map
+            .get("parse")
+            .and_then(|v| v.get::<bool>().copied())
+            .unwrap_or(false);
+
+        match map["event"].get::<String>() {
+            Some(event) if event == "started" => Ok(Event::Started { puzzle, has_parse }),
+            Some(event) if event == "answered" => Ok(Event::Answered { puzzle }),
+            _ => Err("Expected event to be started or answered.".into()),
+        }
     }
+}
 
-    fn parse_time(line: &str) -> Option<(&str, f64)> {
-        // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-        let str_timing = line
-            .split(" samples)")
-            .next()?
-            .split('(')
-            .next_back()?
-            .split('@')
-            .next()?
-            .trim();
-
-        let parsed_timing = match str_timing {
-            s if s.contains("ns") => s.split("ns").next()?.parse::<f64>().ok(),
-            s if s.contains("µs") => parse_to_float(s, "µs").map(|x| x * 1000_f64),
-            s if s.contains("ms") => parse_to_float(s, "ms").map(|x| x * 1_000_000_f64),
-            s => parse_to_float(s, "s").map(|x| x * 1_000_000_000_f64),
-        }?;
-
-        Some((str_timing, parsed_timing))
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use super::child_commands::{Event, parse_event};
+    use super::to_timing;
+    use crate::template::PuzzleId;
+    use crate::template::registry::{answered_event, started_event};
+    use crate::template::runner::{PartResult, Status};
+    use crate::template::stats::Stats;
+    use crate::{day, year};
+
+    fn part(part: u8, answer: Option<&str>, nanos: u64) -> PartResult {
+        let duration = Duration::from_nanos(nanos);
+        PartResult {
+            puzzle: PuzzleId::new(year!(2025), day!(1)).unwrap(),
+            part,
+            answer: answer.map(Into::into),
+            status: if answer.is_some() {
+                Status::Ok
+            } else {
+                Status::None
+            },
+            duration,
+            samples: 100,
+            stats: Stats::single(duration),
+        }
     }
 
-    /// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-    #[cfg(feature = "test_lib")]
-    macro_rules! assert_approx_eq {
-        ($a:expr, $b:expr) => {{
-            let (a, b) = (&$a, &$b);
-            assert!(
-                (*a - *b).abs() < 1.0e-6,
-                "{} is not approximately equal to {}",
-                *a,
-                *b
-            );
-        }};
+    #[test]
+    fn collects_timings() {
+        let res = to_timing(
+            PuzzleId::new(year!(2025), day!(1)).unwrap(),
+            &[part(1, Some("0"), 74), part(2, Some("10"), 74_130_000)],
+        );
+        assert_eq!(res.total_nanos, 74_130_074_f64);
+        assert_eq!(res.part_1.unwrap(), "74.0ns");
+        assert_eq!(res.part_2.unwrap(), "74.1ms");
+        assert_eq!(res.stats[1].unwrap().median, Duration::from_nanos(74));
     }
 
-    #[cfg(feature = "test_lib")]
-    mod tests {
-        use super::parse_exec_time;
-
-        use crate::day;
-
-        #[test]
-        fn parses_execution_times() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: 0 (74.13ns @ 100000 samples)".into(),
-                    "Part 2: 10 (74.13ms @ 99999 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 74130074.13_f64);
-            assert_eq!(res.part_1.unwrap(), "74.13ns");
-            assert_eq!(res.part_2.unwrap(), "74.13ms");
-        }
+    #[test]
+    fn skips_missing_parts() {
+        let res = to_timing(
+            PuzzleId::new(year!(2025), day!(1)).unwrap(),
+            &[part(1, None, 100), part(2, None, 100)],
+        );
+        assert_eq!(res.total_nanos, 0_f64);
+        assert!(res.part_1.is_none());
+        assert!(res.part_2.is_none());
+    }
 
-        #[test]
-        fn parses_with_patterns_in_input() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: @ @ @ ( ) ms (2s @ 5 samples)".into(),
-                    "Part 2: 10s (100ms @ 1 samples)".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 2100000000_f64);
-            assert_eq!(res.part_1.unwrap(), "2s");
-            assert_eq!(res.part_2.unwrap(), "100ms");
-        }
+    #[test]
+    fn parses_results() {
+        let Ok(Event::Result(res)) = parse_event(
+            r#"{"year":2025,"day":1,"part":1,"answer":"@ ( ) ms","status":"ok","error":null,"duration_ns":74,"samples":100000,"stats":{"mean_ns":74,"median_ns":72,"stddev_ns":3,"min_ns":70,"max_ns":90,"p95_ns":85,"outliers":2}}"#,
+        ) else {
+            panic!("expected a result");
+        };
+        assert_eq!(res.answer.as_deref(), Some("@ ( ) ms"));
+        assert_eq!(res.duration, Duration::from_nanos(74));
+        assert_eq!(res.samples, 100_000);
+        assert_eq!(res.stats.max, Duration::from_nanos(90));
+        assert_eq!(res.stats.outliers, 2);
+    }
 
-        #[test]
-        fn parses_missing_parts() {
-            let res = parse_exec_time(
-                &[
-                    "Part 1: ✖        ".into(),
-                    "Part 2: ✖        ".into(),
-                    "".into(),
-                ],
-                day!(1),
-            );
-            assert_approx_eq!(res.total_nanos, 0_f64);
-            assert_eq!(res.part_1.is_none(), true);
-            assert_eq!(res.part_2.is_none(), true);
-        }
+    #[test]
+    fn rejects_human_output() {
+        assert!(parse_event("Part 1: 0 (74.13ns @ 100000 samples)").is_err());
+    }
+
+    #[test]
+    fn parses_started_events() {
+        let puzzle = PuzzleId::new(year!(2025), day!(3)).unwrap();
+        assert_eq!(
+            parse_event(&started_event(puzzle, true)),
+            Ok(Event::Started {
+                puzzle,
+                has_parse: true
+            })
+        );
+        assert_eq!(
+            parse_event(r#"{"event":"started","year":2025,"day":3}"#),
+            Ok(Event::Started {
+                puzzle,
+                has_parse: false
+            })
+        );
+        assert_eq!(
+            parse_event(&answered_event(puzzle, 1)),
+            Ok(Event::Answered { puzzle })
+        );
+        assert!(parse_event(r#"{"event":"stopped","year":2025,"day":3}"#).is_err());
     }
 }
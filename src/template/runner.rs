@@ -1,35 +1,534 @@
 /// Encapsulates code that interacts with solution functions.
+use std::cell::RefCell;
+use std::collections::HashMap;
 use std::fmt::Display;
 use std::hint::black_box;
-use std::io::{Write, stdout};
-use std::process::Output;
+use std::io::{self, Read, Write, stdout};
+use std::panic::{self, AssertUnwindSafe};
+use std::str::FromStr;
+use std::sync::Once;
 use std::time::{Duration, Instant};
-use std::{cmp, env, process};
+use std::{cmp, env, fs, process};
+
+use tinyjson::JsonValue;
 
 use crate::template::ANSI_BOLD;
-use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, aoc_cli};
+use crate::template::answers::{Answers, Verdict};
+use crate::template::context::Context;
+use crate::template::examples::Example;
+use crate::template::registry::answered_event;
+use crate::template::stats::{Stats, nanos_from_json, nanos_to_json};
+use crate::template::{ANSI_ITALIC, ANSI_RESET, Day, PuzzleId, Year, aoc_client};
+
+/// Outcome of running a solution part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    /// The part returned an answer.
+    Ok,
+    /// The part returned `None`.
+    None,
+    /// The part could not be run.
+    Error(String),
+    /// The part was stopped after running longer than its timeout.
+    Timeout,
+}
+
+/// The part number of the parse step of solutions that parse their input once for both parts.
+pub const PARSE_PART: u8 = 0;
+
+/// The answer and timing of a solution part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartResult {
+    pub puzzle: PuzzleId,
+    /// The part number, [`PARSE_PART`] for the parse step.
+    pub part: u8,
+    pub answer: Option<String>,
+    pub status: Status,
+    pub duration: Duration,
+    pub samples: u128,
+    pub stats: Stats,
+}
+
+impl PartResult {
+    /// Result of a part that could not be run, e.g. because its input is missing.
+    #[must_use]
+    pub fn error(puzzle: PuzzleId, part: u8, message: String) -> Self {
+        Self {
+            puzzle,
+            part,
+            answer: None,
+            status: Status::Error(message),
+            duration: Duration::ZERO,
+            samples: 0,
+            stats: Stats::single(Duration::ZERO),
+        }
+    }
+
+    /// Result of a part that was stopped after `duration`.
+    #[must_use]
+    pub fn timeout(puzzle: PuzzleId, part: u8, duration: Duration) -> Self {
+        Self {
+            puzzle,
+            part,
+            answer: None,
+            status: Status::Timeout,
+            duration,
+            samples: 0,
+            stats: Stats::single(duration),
+        }
+    }
+}
+
+/// Output format of the runner, selected with `--format human|json`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Format {
+    /// Results are printed for humans, updated in place while benching.
+    #[default]
+    Human,
+    /// Each part prints a single line with a JSON object, see [`PartResult`].
+    Json,
+}
+
+impl Format {
+    /// Reads the `--format` argument, defaulting to [`Format::Human`]. Unknown or missing values are an error.
+    pub fn from_args() -> Result<Self, String> {
+        let args: Vec<String> = env::args().collect();
+        match args.iter().position(|x| x == "--format") {
+            Some(i) => args
+                .get(i + 1)
+                .ok_or("`--format` expects human or json.".to_string())?
+                .parse(),
+            None => Ok(Format::default()),
+        }
+    }
+
+    /// Like [`Format::from_args`], but exits if the argument is invalid.
+    fn from_args_or_exit() -> Self {
+        Self::from_args().unwrap_or_else(|e| {
+            eprintln!("Error: {e}");
+            process::exit(1);
+        })
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "human" => Ok(Format::Human),
+            "json" => Ok(Format::Json),
+            _ => Err(format!("unknown format `{s}`, expecting human or json")),
+        }
+    }
+}
+
+/// Wall-clock limits of `cargo all` and `cargo time`, a solution is stopped once it exceeds one of them.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Timeouts {
+    /// Limit of the first run of each part and of the parse step. Benching after it only counts towards the day.
+    pub part: Option<Duration>,
+    /// Limit of each day, including benching.
+    pub day: Option<Duration>,
+}
 
-pub fn run_part<I: Copy, T: Display>(func: impl Fn(I) -> Option<T>, input: I, day: Day, part: u8) {
+impl Timeouts {
+    /// Time left until a timeout, given when the day started and when the running part started.
+    /// A part that already answered and is being benched is given as `None`, only the day limit applies to it.
+    /// Returns `None` if there are no limits.
+    #[must_use]
+    pub fn remaining(
+        &self,
+        day_started_at: Instant,
+        part_started_at: Option<Instant>,
+    ) -> Option<Duration> {
+        let part = self
+            .part
+            .zip(part_started_at)
+            .map(|(part, started_at)| part.saturating_sub(started_at.elapsed()));
+        let day = self
+            .day
+            .map(|day| day.saturating_sub(day_started_at.elapsed()));
+        part.into_iter().chain(day).min()
+    }
+}
+
+/// Where a solution reads its input from, selected with `--input <path|->` or `--example [N]`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum InputSource {
+    /// The puzzle input, i.e. `data/<year>/inputs/DD.txt`.
+    #[default]
+    Puzzle,
+    /// Any file, e.g. a colleague's input or a hand-made edge case.
+    Path(String),
+    /// Standard input, selected with `--input -`.
+    Stdin,
+    /// `data/<year>/examples/DD.txt`, or `DD-N.txt` if a number is given.
+    Example(Option<u8>),
+}
+
+impl InputSource {
+    /// Reads the `--input` and `--example` arguments, defaulting to [`InputSource::Puzzle`].
+    pub fn from_args() -> Self {
+        let args: Vec<String> = env::args().collect();
+
+        if let Some(i) = args.iter().position(|x| x == "--input") {
+            return match args.get(i + 1).map(String::as_str) {
+                Some("-") => InputSource::Stdin,
+                Some(path) => InputSource::Path(path.into()),
+                None => {
+                    eprintln!(
+                        "Unexpected command-line input. Format: cargo solve 1 --input <path|->"
+                    );
+                    process::exit(1);
+                }
+            };
+        }
+
+        match args.iter().position(|x| x == "--example") {
+            Some(i) => InputSource::Example(args.get(i + 1).and_then(|n| n.parse().ok())),
+            None => InputSource::Puzzle,
+        }
+    }
+
+    /// The arguments that select this source, to pass them on to a solution binary.
+    #[must_use]
+    pub fn to_args(&self) -> Vec<String> {
+        match self {
+            InputSource::Puzzle => vec![],
+            InputSource::Path(path) => vec!["--input".into(), path.clone()],
+            InputSource::Stdin => vec!["--input".into(), "-".into()],
+            InputSource::Example(None) => vec!["--example".into()],
+            InputSource::Example(Some(n)) => vec!["--example".into(), n.to_string()],
+        }
+    }
+
+    /// Reads the input of `puzzle` and its [`Context`].
+    ///
+    /// The front matter of examples and other files is removed from the input and passed as parameters. Files
+    /// without front matter use the parameters of the puzzle input.
+    pub fn read(&self, puzzle: PuzzleId) -> Result<(String, Context), String> {
+        let (name, content) = match self {
+            InputSource::Puzzle => {
+                let path = puzzle.data_path("inputs", "txt");
+                let input = fs::read_to_string(&path).map_err(|e| format!("{path}: {e}"))?;
+                return Ok((input, Context::for_input(puzzle)));
+            }
+            InputSource::Path(path) => {
+                let content = fs::read_to_string(path).map_err(|e| format!("{path}: {e}"))?;
+                (path.clone(), content)
+            }
+            InputSource::Stdin => {
+                let mut content = String::new();
+                io::stdin()
+                    .read_to_string(&mut content)
+                    .map_err(|e| format!("stdin: {e}"))?;
+                ("stdin".into(), content)
+            }
+            InputSource::Example(n) => {
+                let path = puzzle.example_path(*n);
+                let content = fs::read_to_string(&path).map_err(|e| format!("{path}: {e}"))?;
+                let example =
+                    Example::parse(&path, &content).map_err(|e| format!("{path}: {e}"))?;
+                let context = Context::for_example(&example);
+                return Ok((example.input, context));
+            }
+        };
+
+        let file = Example::parse(&name, &content).map_err(|e| format!("{name}: {e}"))?;
+        let context = if file.metadata.is_empty() {
+            Context::for_input(puzzle)
+        } else {
+            Context::for_example(&file)
+        };
+        Ok((file.input, context))
+    }
+}
+
+/// Reads the input selected on the command line, see [`InputSource`]. Exits if it can't be read, or if an answer
+/// should be submitted for another input than the puzzle input.
+pub fn read_input(puzzle: PuzzleId) -> (String, Context) {
+    let source = InputSource::from_args();
+
+    if source != InputSource::Puzzle && env::args().any(|x| x == "--submit") {
+        eprintln!("Answers can only be submitted for the puzzle input.");
+        process::exit(1);
+    }
+
+    source.read(puzzle).unwrap_or_else(|e| {
+        eprintln!("Could not read input: {e}");
+        process::exit(1);
+    })
+}
+
+/// The return value of a solution part: an `Option`, or a `Result` whose error is reported as a failure of the part.
+pub trait Answer {
+    /// The answer of the part, `None` if it has none yet, or the error message if the part failed.
+    fn to_answer(&self) -> Result<Option<String>, String>;
+}
+
+impl<T: Display> Answer for Option<T> {
+    fn to_answer(&self) -> Result<Option<String>, String> {
+        Ok(self.as_ref().map(ToString::to_string))
+    }
+}
+
+impl<T: Display, E: Display> Answer for Result<T, E> {
+    fn to_answer(&self) -> Result<Option<String>, String> {
+        match self {
+            Ok(answer) => Ok(Some(answer.to_string())),
+            Err(e) => Err(e.to_string()),
+        }
+    }
+}
+
+/// A solution part, which takes the input and, optionally, the [`Context`] of the input.
+///
+/// `Marker` is [`WithoutContext`] or [`WithContext`], which lets both kinds of functions implement the trait.
+pub trait Part<I, R, Marker> {
+    fn call(&self, input: I, context: &Context) -> R;
+}
+
+/// Marks parts of the form `fn(input) -> impl Answer`.
+pub struct WithoutContext;
+
+/// Marks parts of the form `fn(input, &Context) -> impl Answer`.
+pub struct WithContext;
+
+impl<F: Fn(I) -> R, I, R> Part<I, R, WithoutContext> for F {
+    fn call(&self, input: I, _: &Context) -> R {
+        self(input)
+    }
+}
+
+impl<F: Fn(I, &Context) -> R, I, R> Part<I, R, WithContext> for F {
+    fn call(&self, input: I, context: &Context) -> R {
+        self(input, context)
+    }
+}
+
+pub fn run_part<I: Copy, R: Answer, M>(
+    func: impl Part<I, R, M>,
+    input: I,
+    context: &Context,
+    puzzle: PuzzleId,
+    part: u8,
+) {
+    let is_timed = env::args().any(|x| x == "--time");
+    let result = execute_part(
+        func,
+        input,
+        context,
+        puzzle,
+        part,
+        is_timed,
+        Format::from_args_or_exit(),
+    );
+
+    if let Some(answer) = result.answer {
+        submit_result(answer, puzzle, part);
+    }
+}
+
+/// Parses the input of a solution and prints the time it took. Exits if parsing panics.
+pub fn run_parse<'a, T>(func: impl Fn(&'a str) -> T, input: &'a str, puzzle: PuzzleId) -> T {
+    let is_timed = env::args().any(|x| x == "--time");
+    match execute_parse(func, input, puzzle, is_timed, Format::from_args_or_exit()) {
+        (Some(parsed), _) => parsed,
+        (None, _) => process::exit(1),
+    }
+}
+
+/// Runs the parse step of a solution and prints its timing in `format`.
+/// Returns `None` as parsed input if parsing panicked, the result then holds the panic message.
+pub fn execute_parse<'a, T>(
+    func: impl Fn(&'a str) -> T,
+    input: &'a str,
+    puzzle: PuzzleId,
+    is_timed: bool,
+    format: Format,
+) -> (Option<T>, PartResult) {
+    let func = |input| catch_panic(|| func(input));
+    let (parsed, stats, samples) = run_timed(func, input, is_timed, |parsed| {
+        if is_timed && parsed.is_ok() {
+            print_benching(puzzle, PARSE_PART, "Parse:", format);
+        }
+        parsed.is_ok()
+    });
+
+    let (parsed, status) = match parsed {
+        Ok(parsed) => (Some(parsed), Status::Ok),
+        Err(message) => (None, Status::Error(message)),
+    };
+
+    let result = PartResult {
+        puzzle,
+        part: PARSE_PART,
+        answer: None,
+        status,
+        duration: stats.mean,
+        samples,
+        stats,
+    };
+
+    print_part(&result, format);
+    (parsed, result)
+}
+
+/// Runs a solution part and prints its result in `format`.
+///
+/// Panics and errors returned by the part are reported as [`Status::Error`], failed parts are not benched.
+pub fn execute_part<I: Copy, R: Answer, M>(
+    func: impl Part<I, R, M>,
+    input: I,
+    context: &Context,
+    puzzle: PuzzleId,
+    part: u8,
+    is_timed: bool,
+    format: Format,
+) -> PartResult {
     let part_str = format!("Part {part}");
 
-    let (result, duration, samples) =
-        run_timed(func, input, |result| print_result(result, &part_str, ""));
+    let func = |input| catch_panic(|| func.call(input, context));
+    let (result, stats, samples) = run_timed(func, input, is_timed, |result| {
+        let answer = answer_of(result);
+        if format == Format::Human
+            && let Ok(answer) = &answer
+        {
+            print_result(answer, &part_str, "");
+        }
+        if is_timed && answer.is_ok() {
+            print_benching(puzzle, part, "", format);
+        }
+        answer.is_ok()
+    });
 
-    print_result(&result, &part_str, &format_duration(&duration, samples));
+    let (answer, status) = match answer_of(&result) {
+        Ok(Some(answer)) => (Some(answer), Status::Ok),
+        Ok(None) => (None, Status::None),
+        Err(message) => (None, Status::Error(message)),
+    };
 
-    if let Some(result) = result {
-        submit_result(result, day, part);
+    let result = PartResult {
+        puzzle,
+        part,
+        answer,
+        status,
+        duration: stats.mean,
+        samples,
+        stats,
+    };
+
+    print_part(&result, format);
+    result
+}
+
+/// Announces that the first run of `part` returned and benching starts, see [`answered_event`].
+fn print_benching(puzzle: PuzzleId, part: u8, prefix: &str, format: Format) {
+    match format {
+        Format::Human => print!("{prefix} > {ANSI_ITALIC}benching{ANSI_RESET}"),
+        Format::Json => println!("{}", answered_event(puzzle, part)),
+    }
+    let _ = stdout().flush();
+}
+
+fn answer_of<R: Answer>(result: &Result<R, String>) -> Result<Option<String>, String> {
+    result
+        .as_ref()
+        .map_err(Clone::clone)
+        .and_then(Answer::to_answer)
+}
+
+thread_local! {
+    /// Location of the last panic caught by [`catch_panic`], `None` outside of it.
+    static CAUGHT_PANIC: RefCell<Option<Option<String>>> = const { RefCell::new(None) };
+}
+
+/// Calls `func`, returning the panic message as error if it panics.
+///
+/// The panic is not printed by the panic hook, its location is part of the message instead.
+fn catch_panic<T>(func: impl FnOnce() -> T) -> Result<T, String> {
+    static INSTALL_HOOK: Once = Once::new();
+    INSTALL_HOOK.call_once(|| {
+        let default_hook = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            let is_caught = CAUGHT_PANIC.with_borrow_mut(|caught| match caught {
+                Some(location) => {
+                    *location = info.location().map(ToString::to_string);
+                    true
+                }
+                None => false,
+            });
+            if !is_caught {
+                default_hook(info);
+            }
+        }));
+    });
+
+    CAUGHT_PANIC.set(Some(None));
+    let result = panic::catch_unwind(AssertUnwindSafe(func));
+    let location = CAUGHT_PANIC.take().flatten();
+
+    result.map_err(|payload| {
+        let message = payload
+            .downcast_ref::<&str>()
+            .map(ToString::to_string)
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown cause".into());
+        match location {
+            Some(location) => format!("panicked at {location}: {message}"),
+            None => format!("panicked: {message}"),
+        }
+    })
+}
+
+/// Prints the final result of a part in `format`.
+pub fn print_part(result: &PartResult, format: Format) {
+    if format == Format::Json {
+        println!("{}", JsonValue::from(result).stringify().unwrap());
+        return;
+    }
+
+    let part_str = if result.part == PARSE_PART {
+        "Parse".to_string()
+    } else {
+        format!("Part {}", result.part)
+    };
+    match &result.status {
+        Status::Error(message) => {
+            print!("\r");
+            println!("{part_str}: ✖ {message}");
+        }
+        Status::Timeout => {
+            print!("\r");
+            println!("{part_str}: ✖ TIMEOUT ({:.1?})", result.duration);
+        }
+        _ if result.part == PARSE_PART => {
+            print!("\r");
+            println!(
+                "Parse:{}",
+                format_duration(&result.duration, result.samples)
+            );
+        }
+        _ => print_result(
+            &result.answer,
+            &part_str,
+            &format_duration(&result.duration, result.samples),
+        ),
     }
 }
 
-/// Run a solution part. The behavior differs depending on whether we are running a release or debug build:
-///  1. in debug, the function is executed once.
-///  2. in release, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+/// Run a solution part. The behavior differs depending on whether the part is timed:
+///  1. by default, the function is executed once.
+///  2. when timed, the function is benched (approx. 1 second of execution time or 10 samples, whatever take longer.)
+///
+/// The hook is called with the result of the first run and returns whether the function may be benched.
 fn run_timed<I: Copy, T>(
     func: impl Fn(I) -> T,
     input: I,
-    hook: impl Fn(&T),
-) -> (T, Duration, u128) {
+    is_timed: bool,
+    hook: impl Fn(&T) -> bool,
+) -> (T, Stats, u128) {
     let timer = Instant::now();
     let result = {
         #[cfg(feature = "dhat-heap")]
@@ -39,26 +538,24 @@ fn run_timed<I: Copy, T>(
     };
     let base_time = timer.elapsed();
 
-    hook(&result);
-
-    let run = if std::env::args().any(|x| x == "--time") {
+    let run = if hook(&result) && is_timed {
         bench(func, input, &base_time)
     } else {
-        (base_time, 1)
+        (Stats::single(base_time), 1)
     };
 
     (result, run.0, run.1)
 }
 
-fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Duration, u128) {
-    let mut stdout = stdout();
-
-    print!(" > {ANSI_ITALIC}benching{ANSI_RESET}");
-    let _ = stdout.flush();
-
+/// Benches a function after a warm-up phase of a tenth of the iterations, which are not measured.
+fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (Stats, u128) {
     let bench_iterations =
         (Duration::from_secs(1).as_nanos() / cmp::max(base_time.as_nanos(), 10)).clamp(10, 10000);
 
+    for _ in 0..bench_iterations / 10 {
+        black_box(func(black_box(input)));
+    }
+
     let mut timers: Vec<Duration> = vec![];
 
     for _ in 0..bench_iterations {
@@ -68,20 +565,11 @@ fn bench<I: Copy, T>(func: impl Fn(I) -> T, input: I, base_time: &Duration) -> (
     }
 
     (
-        #[allow(clippy::cast_possible_truncation)]
-        Duration::from_nanos(average_duration(&timers) as u64),
+        Stats::from_samples(&timers).unwrap_or_else(|| Stats::single(*base_time)),
         bench_iterations,
     )
 }
 
-fn average_duration(numbers: &[Duration]) -> u128 {
-    numbers
-        .iter()
-        .map(std::time::Duration::as_nanos)
-        .sum::<u128>()
-        / numbers.len() as u128
-}
-
 fn format_duration(duration: &Duration, samples: u128) -> String {
     if samples == 1 {
         format!(" ({duration:.1?})")
@@ -127,12 +615,13 @@ fn print_result<T: Display>(result: &Option<T>, part: &str, duration_str: &str)
 
 /// Parse the arguments passed to `solve` and try to submit one part of the solution if:
 ///  1. we are in `--release` mode.
-///  2. aoc-cli is installed.
+///  2. a session cookie is configured.
+///  3. the answer ledger in `data/answers.json` does not rule the answer out.
 fn submit_result<T: Display>(
     result: T,
-    day: Day,
+    puzzle: PuzzleId,
     part: u8,
-) -> Option<Result<Output, aoc_cli::AocCommandError>> {
+) -> Option<Result<(), aoc_client::AocClientError>> {
     let args: Vec<String> = env::args().collect();
 
     if !args.contains(&"--submit".into()) {
@@ -155,13 +644,261 @@ fn submit_result<T: Display>(
         return None;
     }
 
-    if aoc_cli::check().is_err() {
-        eprintln!(
-            "command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it."
-        );
+    if let Err(e) = aoc_client::read_session() {
+        eprintln!("{e}");
         process::exit(1);
     }
 
-    println!("Submitting result via aoc-cli...");
-    Some(aoc_cli::submit(day, part, &result.to_string()))
+    let answer = result.to_string();
+    let mut answers = match Answers::read_from_file() {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("Not submitting {answer}: failed to read the answer ledger: {e}");
+            return None;
+        }
+    };
+
+    if let Err(refusal) = answers.check(puzzle, part, &answer) {
+        eprintln!("Not submitting {answer}: {refusal}");
+        return None;
+    }
+
+    println!("Submitting result...");
+    let submission = aoc_client::submit(puzzle, part, &answer);
+    match &submission {
+        Ok(()) => println!("🎄 That's the right answer!"),
+        Err(e) => eprintln!("Submission failed: {e}"),
+    }
+
+    if let Some(verdict) = Verdict::from_submission(&submission) {
+        answers.record(puzzle, part, &answer, verdict);
+        if let Err(e) = answers.store_file() {
+            eprintln!("Failed to store answer ledger: {e}");
+        }
+    }
+
+    Some(submission)
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&PartResult> for JsonValue {
+    fn from(value: &PartResult) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert(
+            "year".into(),
+            JsonValue::Number(f64::from(value.puzzle.year().into_inner())),
+        );
+        map.insert(
+            "day".into(),
+            JsonValue::Number(f64::from(value.puzzle.day().into_inner())),
+        );
+        map.insert("part".into(), JsonValue::Number(f64::from(value.part)));
+        map.insert(
+            "answer".into(),
+            match &value.answer {
+                Some(answer) => JsonValue::String(answer.clone()),
+                None => JsonValue::Null,
+            },
+        );
+
+        let (status, error) = match &value.status {
+            Status::Ok => ("ok", JsonValue::Null),
+            Status::None => ("none", JsonValue::Null),
+            Status::Error(message) => ("error", JsonValue::String(message.clone())),
+            Status::Timeout => ("timeout", JsonValue::Null),
+        };
+        map.insert("status".into(), JsonValue::String(status.into()));
+        map.insert("error".into(), error);
+
+        map.insert("duration_ns".into(), nanos_to_json(value.duration));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("samples".into(), JsonValue::Number(value.samples as f64));
+
+        map.insert("stats".into(), JsonValue::from(&value.stats));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for PartResult {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected part result to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected result.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let puzzle = Year::new(number("year")? as u16)
+            .zip(Day::new(number("day")? as u8))
+            .and_then(|(year, day)| PuzzleId::new(year, day))
+            .ok_or("Expected result.year and result.day to be a puzzle.")?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let part = number("part")? as u8;
+
+        let answer = json
+            .get("answer")
+            .map(|v| if v.is_null() { None } else { v.get::<String>() })
+            .ok_or("Expected result.answer to be null or string.")?
+            .cloned();
+
+        let status = match json.get("status").and_then(|v| v.get::<String>()) {
+            Some(status) if status == "ok" => Status::Ok,
+            Some(status) if status == "none" => Status::None,
+            Some(status) if status == "timeout" => Status::Timeout,
+            Some(status) if status == "error" => Status::Error(
+                json.get("error")
+                    .and_then(|v| v.get::<String>())
+                    .cloned()
+                    .unwrap_or_default(),
+            ),
+            _ => {
+                return Err("Expected result.status to be one of ok, none, error, timeout.".into());
+            }
+        };
+
+        let stats = json
+            .get("stats")
+            .ok_or("Expected result.stats to be an object.")
+            .map_err(String::from)
+            .and_then(Stats::try_from)?;
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let samples = number("samples")? as u128;
+
+        Ok(PartResult {
+            puzzle,
+            part,
+            answer,
+            status,
+            duration: nanos_from_json(number("duration_ns")?),
+            samples,
+            stats,
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::str::FromStr;
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Format, InputSource, PartResult, Status, execute_parse, execute_part};
+    use crate::template::PuzzleId;
+    use crate::template::context::Context;
+    use crate::template::stats::Stats;
+    use crate::{day, year};
+
+    fn result(status: Status, answer: Option<&str>) -> PartResult {
+        PartResult {
+            puzzle: PuzzleId::new(year!(2025), day!(3)).unwrap(),
+            part: 2,
+            answer: answer.map(Into::into),
+            status,
+            duration: Duration::from_nanos(1500),
+            samples: 100,
+            stats: Stats::from_samples(&[
+                Duration::from_nanos(1000),
+                Duration::from_nanos(1500),
+                Duration::from_nanos(4000),
+            ])
+            .unwrap(),
+        }
+    }
+
+    fn roundtrip(result: &PartResult) -> PartResult {
+        let line = JsonValue::from(result).stringify().unwrap();
+        assert!(!line.contains('\n'));
+        PartResult::try_from(&JsonValue::from_str(&line).unwrap()).unwrap()
+    }
+
+    #[test]
+    fn serializes_answers() {
+        // answers may contain anything, including characters the human output uses as separators.
+        let res = result(Status::Ok, Some("(1 @ 2 samples)\nabc"));
+        assert_eq!(roundtrip(&res), res);
+    }
+
+    #[test]
+    fn serializes_missing_answers_and_errors() {
+        let res = result(Status::None, None);
+        assert_eq!(roundtrip(&res), res);
+
+        let res = result(Status::Error("missing input".into()), None);
+        assert_eq!(roundtrip(&res), res);
+    }
+
+    #[test]
+    fn rejects_unknown_status() {
+        let json = JsonValue::from_str(
+            r#"{"year": 2025, "day": 1, "part": 1, "answer": null, "status": "maybe",
+                "duration_ns": 1, "samples": 1, "stats": {"mean_ns": 1, "median_ns": 1, "stddev_ns": 0,
+                "min_ns": 1, "max_ns": 1, "p95_ns": 1, "outliers": 0}}"#,
+        )
+        .unwrap();
+        assert!(PartResult::try_from(&json).is_err());
+    }
+
+    #[test]
+    fn reads_examples_with_parameters() {
+        let puzzle = PuzzleId::new(year!(2025), day!(8)).unwrap();
+        let (input, context) = InputSource::Example(None).read(puzzle).unwrap();
+        assert!(!input.starts_with("---"));
+        assert_eq!(context.get::<usize>("connections"), Some(10));
+        assert!(InputSource::Example(Some(99)).read(puzzle).is_err());
+    }
+
+    #[test]
+    fn forwards_input_arguments() {
+        assert!(InputSource::Puzzle.to_args().is_empty());
+        assert_eq!(InputSource::Stdin.to_args(), ["--input", "-"]);
+        assert_eq!(InputSource::Example(Some(2)).to_args(), ["--example", "2"]);
+    }
+
+    #[test]
+    fn reports_panics_and_errors() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let context = Context::default();
+        let run = |func: fn(&str) -> Result<u64, String>| {
+            execute_part(func, "", &context, puzzle, 1, true, Format::Json).status
+        };
+
+        assert_eq!(run(|_| Ok(3)), Status::Ok);
+        assert_eq!(
+            run(|_| Err("bad input".into())),
+            Status::Error("bad input".into())
+        );
+        assert!(matches!(
+            run(|_| panic!("Unknown direction")),
+            Status::Error(message) if message.starts_with("panicked at src/template/runner.rs:")
+                && message.ends_with(": Unknown direction")
+        ));
+    }
+
+    #[test]
+    fn reports_panics_while_parsing() {
+        let puzzle = PuzzleId::new(year!(2025), day!(1)).unwrap();
+        let (parsed, result) = execute_parse(
+            |_| -> u64 { panic!("empty") },
+            "",
+            puzzle,
+            false,
+            Format::Json,
+        );
+        assert!(parsed.is_none());
+        assert!(matches!(result.status, Status::Error(message) if message.ends_with(": empty")));
+    }
 }
@@ -0,0 +1,264 @@
+//! Summary statistics of benchmark samples.
+
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::str::FromStr;
+use std::time::Duration;
+
+use tinyjson::JsonValue;
+
+/// Summary of the samples taken while benching a part. All values are equal to the duration of a
+/// single run if the part was not benched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Stats {
+    pub mean: Duration,
+    pub median: Duration,
+    pub stddev: Duration,
+    pub min: Duration,
+    pub max: Duration,
+    pub p95: Duration,
+    /// Number of samples outside of the Tukey fences, i.e. more than 1.5 IQR below the first or above the third quartile.
+    pub outliers: usize,
+}
+
+impl Stats {
+    /// Statistics of a single run.
+    #[must_use]
+    pub fn single(duration: Duration) -> Self {
+        Self {
+            mean: duration,
+            median: duration,
+            stddev: Duration::ZERO,
+            min: duration,
+            max: duration,
+            p95: duration,
+            outliers: 0,
+        }
+    }
+
+    /// Computes the statistics of `samples`. Returns `None` if there are no samples.
+    #[must_use]
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let len = sorted.len();
+        let min = *sorted.first()?;
+        let max = *sorted.last()?;
+
+        let nanos = sorted.iter().map(Duration::as_nanos);
+        let mean = nanos.clone().sum::<u128>() / len as u128;
+
+        #[allow(clippy::cast_precision_loss)]
+        let variance = nanos.map(|x| (x as f64 - mean as f64).powi(2)).sum::<f64>() / len as f64;
+
+        let median = if len.is_multiple_of(2) {
+            (sorted[len / 2 - 1] + sorted[len / 2]) / 2
+        } else {
+            sorted[len / 2]
+        };
+
+        let q1 = percentile(&sorted, 25);
+        let q3 = percentile(&sorted, 75);
+        let iqr = q3 - q1;
+        let lower_fence = q1.saturating_sub(iqr * 3 / 2);
+        let upper_fence = q3 + iqr * 3 / 2;
+
+        #[allow(clippy::cast_possible_truncation)]
+        Some(Self {
+            mean: Duration::from_nanos(mean as u64),
+            median,
+            stddev: Duration::from_secs_f64(variance.sqrt() / 1e9),
+            min,
+            max,
+            p95: percentile(&sorted, 95),
+            outliers: sorted
+                .iter()
+                .filter(|x| **x < lower_fence || **x > upper_fence)
+                .count(),
+        })
+    }
+
+    /// Returns the value of `statistic`.
+    #[must_use]
+    pub fn get(&self, statistic: Statistic) -> Duration {
+        match statistic {
+            Statistic::Mean => self.mean,
+            Statistic::Median => self.median,
+            Statistic::Min => self.min,
+            Statistic::Max => self.max,
+            Statistic::P95 => self.p95,
+        }
+    }
+}
+
+/// Nearest-rank percentile of sorted, non-empty samples.
+fn percentile(sorted: &[Duration], p: usize) -> Duration {
+    let rank = (p * sorted.len()).div_ceil(100).max(1);
+    sorted[rank - 1]
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// A statistic that can be selected for display, e.g. in the README benchmarks.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Statistic {
+    Mean,
+    #[default]
+    Median,
+    Min,
+    Max,
+    P95,
+}
+
+impl FromStr for Statistic {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "mean" => Ok(Statistic::Mean),
+            "median" => Ok(Statistic::Median),
+            "min" => Ok(Statistic::Min),
+            "max" => Ok(Statistic::Max),
+            "p95" => Ok(Statistic::P95),
+            _ => Err(format!(
+                "unknown statistic `{s}`, expecting one of mean, median, min, max, p95"
+            )),
+        }
+    }
+}
+
+impl Display for Statistic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Statistic::Mean => "mean",
+            Statistic::Median => "median",
+            Statistic::Min => "min",
+            Statistic::Max => "max",
+            Statistic::P95 => "p95",
+        })
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl From<&Stats> for JsonValue {
+    fn from(value: &Stats) -> Self {
+        let mut map: HashMap<String, JsonValue> = HashMap::new();
+
+        map.insert("mean_ns".into(), nanos_to_json(value.mean));
+        map.insert("median_ns".into(), nanos_to_json(value.median));
+        map.insert("stddev_ns".into(), nanos_to_json(value.stddev));
+        map.insert("min_ns".into(), nanos_to_json(value.min));
+        map.insert("max_ns".into(), nanos_to_json(value.max));
+        map.insert("p95_ns".into(), nanos_to_json(value.p95));
+        #[allow(clippy::cast_precision_loss)]
+        map.insert("outliers".into(), JsonValue::Number(value.outliers as f64));
+
+        JsonValue::Object(map)
+    }
+}
+
+impl TryFrom<&JsonValue> for Stats {
+    type Error = String;
+
+    fn try_from(value: &JsonValue) -> Result<Self, Self::Error> {
+        let json = value
+            .get::<HashMap<String, JsonValue>>()
+            .ok_or("Expected stats to be a JSON object.")?;
+
+        let number = |key: &str| {
+            json.get(key)
+                .and_then(|v| v.get::<f64>().copied())
+                .ok_or(format!("Expected stats.{key} to be a number."))
+        };
+
+        #[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+        let outliers = number("outliers")? as usize;
+
+        Ok(Stats {
+            mean: nanos_from_json(number("mean_ns")?),
+            median: nanos_from_json(number("median_ns")?),
+            stddev: nanos_from_json(number("stddev_ns")?),
+            min: nanos_from_json(number("min_ns")?),
+            max: nanos_from_json(number("max_ns")?),
+            p95: nanos_from_json(number("p95_ns")?),
+            outliers,
+        })
+    }
+}
+
+#[allow(clippy::cast_precision_loss)]
+pub(crate) fn nanos_to_json(duration: Duration) -> JsonValue {
+    JsonValue::Number(duration.as_nanos() as f64)
+}
+
+#[allow(clippy::cast_possible_truncation, clippy::cast_sign_loss)]
+pub(crate) fn nanos_from_json(nanos: f64) -> Duration {
+    Duration::from_nanos(nanos as u64)
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use std::time::Duration;
+
+    use tinyjson::JsonValue;
+
+    use super::{Statistic, Stats};
+
+    fn nanos(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|x| Duration::from_nanos(*x)).collect()
+    }
+
+    #[test]
+    fn computes_stats() {
+        let stats = Stats::from_samples(&nanos(&[5, 1, 4, 2, 3])).unwrap();
+        assert_eq!(stats.mean, Duration::from_nanos(3));
+        assert_eq!(stats.median, Duration::from_nanos(3));
+        assert_eq!(stats.min, Duration::from_nanos(1));
+        assert_eq!(stats.max, Duration::from_nanos(5));
+        assert_eq!(stats.p95, Duration::from_nanos(5));
+        assert_eq!(stats.stddev.as_nanos(), 1);
+        assert_eq!(stats.outliers, 0);
+    }
+
+    #[test]
+    fn computes_median_of_even_samples() {
+        let stats = Stats::from_samples(&nanos(&[1, 2, 4, 10])).unwrap();
+        assert_eq!(stats.median, Duration::from_nanos(3));
+    }
+
+    #[test]
+    fn detects_outliers() {
+        let mut samples = nanos(&[100; 19]);
+        samples.push(Duration::from_nanos(10_000));
+        let stats = Stats::from_samples(&samples).unwrap();
+        assert_eq!(stats.outliers, 1);
+        assert_eq!(stats.median, Duration::from_nanos(100));
+        assert_eq!(stats.p95, Duration::from_nanos(100));
+        assert_eq!(stats.mean, Duration::from_nanos(595));
+    }
+
+    #[test]
+    fn handles_empty_samples() {
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn selects_statistics() {
+        let stats = Stats::from_samples(&nanos(&[1, 2, 9])).unwrap();
+        assert_eq!(stats.get(Statistic::Mean), Duration::from_nanos(4));
+        assert_eq!(stats.get(Statistic::Median), Duration::from_nanos(2));
+        assert_eq!("p95".parse::<Statistic>(), Ok(Statistic::P95));
+        assert!("average".parse::<Statistic>().is_err());
+    }
+
+    #[test]
+    fn serializes_stats() {
+        let stats = Stats::from_samples(&nanos(&[10, 20, 30, 1000])).unwrap();
+        let json = JsonValue::from(&stats);
+        assert_eq!(Stats::try_from(&json), Ok(stats));
+    }
+}
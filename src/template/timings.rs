@@ -1,17 +1,63 @@
 use std::{collections::HashMap, fs, io::Error, str::FromStr};
 use tinyjson::JsonValue;
 
-use crate::template::Day;
+use crate::template::stats::{Statistic, Stats};
+use crate::template::{Day, PuzzleId, Year};
 
 static TIMINGS_FILE_PATH: &str = "./data/timings.json";
 
+/// Stored instead of a duration for parts that were stopped after running into a timeout.
+pub const TIMEOUT: &str = "TIMEOUT";
+
 /// Represents benchmark times for a single day.
 #[derive(Clone, Debug)]
 pub struct Timing {
+    pub year: Year,
     pub day: Day,
+    /// Duration of the parse step of solutions that parse their input once for both parts.
+    pub parse: Option<String>,
     pub part_1: Option<String>,
     pub part_2: Option<String>,
     pub total_nanos: f64,
+    /// Benchmark statistics of the parse step and both parts, indexed by part number.
+    /// Missing for timings stored before statistics were collected.
+    pub stats: [Option<Stats>; 3],
+}
+
+impl Timing {
+    pub fn puzzle(&self) -> PuzzleId {
+        PuzzleId::new_unchecked(self.year, self.day)
+    }
+
+    /// Formats the duration of `part` (0 for the parse step) as `statistic`, falling back to the stored mean if there are no statistics.
+    pub fn format_part(&self, part: u8, statistic: Statistic) -> Option<String> {
+        let duration = match part {
+            0 => &self.parse,
+            1 => &self.part_1,
+            _ => &self.part_2,
+        };
+        let stats = &self.stats[usize::from(part.min(2))];
+        let duration = duration.as_ref()?;
+
+        Some(match stats {
+            Some(stats) => format!("{:.1?}", stats.get(statistic)),
+            None => duration.clone(),
+        })
+    }
+
+    /// Sum of `statistic` over all parts as nanos, falling back to the stored total if there are no statistics.
+    pub fn total_nanos_of(&self, statistic: Statistic) -> f64 {
+        if self.stats.iter().all(Option::is_none) {
+            return self.total_nanos;
+        }
+
+        #[allow(clippy::cast_precision_loss)]
+        self.stats
+            .iter()
+            .flatten()
+            .map(|stats| stats.get(statistic).as_nanos() as f64)
+            .sum()
+    }
 }
 
 /// Represents benchmark times for a set of days.
@@ -46,12 +92,12 @@ impl Timings {
         }
 
         for timing in &self.data {
-            if !data.iter().any(|t| t.day == timing.day) {
+            if !data.iter().any(|t| t.puzzle() == timing.puzzle()) {
                 data.push(timing.clone());
             }
         }
 
-        data.sort_unstable_by(|a, b| a.day.cmp(&b.day));
+        data.sort_unstable_by_key(Timing::puzzle);
         Timings { data }
     }
 
@@ -60,10 +106,23 @@ impl Timings {
         self.data.iter().map(|x| x.total_nanos).sum::<f64>() / 1_000_000_f64
     }
 
-    pub fn is_day_complete(&self, day: Day) -> bool {
+    /// Sum up `statistic` of all timings as millis, see [`Timing::total_nanos_of`].
+    pub fn total_millis_of(&self, statistic: Statistic) -> f64 {
         self.data
             .iter()
-            .any(|t| t.day == day && t.part_1.is_some() && t.part_2.is_some())
+            .map(|x| x.total_nanos_of(statistic))
+            .sum::<f64>()
+            / 1_000_000_f64
+    }
+
+    /// Returns `true` if every part of `puzzle` was benched without timing out. The last day of an event only has one part.
+    pub fn is_day_complete(&self, puzzle: PuzzleId) -> bool {
+        let is_benched = |part: &Option<String>| part.as_ref().is_some_and(|part| part != TIMEOUT);
+        self.data.iter().any(|t| {
+            t.puzzle() == puzzle
+                && is_benched(&t.part_1)
+                && (is_benched(&t.part_2) || puzzle.part_count() == 1)
+        })
     }
 }
 
@@ -97,23 +156,51 @@ impl TryFrom<String> for Timings {
             .ok_or("expected `json.data` to be an array.")?;
 
         Ok(Timings {
-            data: json_data
-                .iter()
-                .map(Timing::try_from)
-                .collect::<Result<_, _>>()?,
+            data: timings_in_event(json_data)?,
         })
     }
 }
 
+/// Parses stored timings, skipping days that are not part of their year's event.
+///
+/// `Timing::puzzle` relies on every stored day being part of its year's event.
+pub(crate) fn timings_in_event(json: &[JsonValue]) -> Result<Vec<Timing>, String> {
+    let data: Vec<Timing> = json
+        .iter()
+        .map(Timing::try_from)
+        .collect::<Result<_, _>>()?;
+
+    Ok(data
+        .into_iter()
+        .filter(|timing| {
+            let is_valid = PuzzleId::new(timing.year, timing.day).is_some();
+            if !is_valid {
+                eprintln!(
+                    "Skipping timing of {} day {}, which is not part of the event.",
+                    timing.year, timing.day
+                );
+            }
+            is_valid
+        })
+        .collect())
+}
+
 /* -------------------------------------------------------------------------- */
 
 impl From<&Timing> for JsonValue {
     fn from(value: &Timing) -> Self {
         let mut map: HashMap<String, JsonValue> = HashMap::new();
 
+        map.insert("year".into(), JsonValue::String(value.year.to_string()));
         map.insert("day".into(), JsonValue::String(value.day.to_string()));
         map.insert("total_nanos".into(), JsonValue::Number(value.total_nanos));
 
+        let parse = value
+            .parse
+            .clone()
+            .map_or(JsonValue::Null, JsonValue::String);
+        map.insert("parse".into(), parse);
+
         let part_1 = value.part_1.clone().map(JsonValue::String);
         let part_2 = value.part_2.clone().map(JsonValue::String);
 
@@ -133,6 +220,14 @@ impl From<&Timing> for JsonValue {
             },
         );
 
+        let keys = ["parse_stats", "part_1_stats", "part_2_stats"];
+        for (key, stats) in keys.iter().zip(&value.stats) {
+            map.insert(
+                (*key).into(),
+                stats.as_ref().map_or(JsonValue::Null, JsonValue::from),
+            );
+        }
+
         JsonValue::Object(map)
     }
 }
@@ -145,12 +240,27 @@ impl TryFrom<&JsonValue> for Timing {
             .get::<HashMap<String, JsonValue>>()
             .ok_or("Expected timing to be a JSON object.")?;
 
+        let year = json
+            .get("year")
+            .and_then(|v| v.get::<String>())
+            .and_then(|year| Year::from_str(year).ok())
+            .ok_or("Expected timing.year to be a Year struct.")?;
+
         let day = json
             .get("day")
             .and_then(|v| v.get::<String>())
             .and_then(|day| Day::from_str(day).ok())
             .ok_or("Expected timing.day to be a Day struct.")?;
 
+        // the parse step is optional and missing in timings stored before it was timed separately.
+        let parse = match json.get("parse") {
+            None | Some(JsonValue::Null) => None,
+            Some(v) => Some(
+                v.get::<String>()
+                    .ok_or("Expected timing.parse to be null or string.")?,
+            ),
+        };
+
         let part_1 = json
             .get("part_1")
             .map(|v| if v.is_null() { None } else { v.get::<String>() })
@@ -166,11 +276,25 @@ impl TryFrom<&JsonValue> for Timing {
             .and_then(|v| v.get::<f64>().copied())
             .ok_or("Expected timing.total_nanos to be a number.")?;
 
+        let stats = |key: &str| match json.get(key) {
+            None | Some(JsonValue::Null) => Ok(None),
+            Some(v) => Stats::try_from(v)
+                .map(Some)
+                .map_err(|e| format!("Expected timing.{key} to be null or stats: {e}")),
+        };
+
         Ok(Timing {
+            year,
             day,
+            parse: parse.cloned(),
             part_1: part_1.cloned(),
             part_2: part_2.cloned(),
             total_nanos,
+            stats: [
+                stats("parse_stats")?,
+                stats("part_1_stats")?,
+                stats("part_2_stats")?,
+            ],
         })
     }
 }
@@ -179,7 +303,7 @@ impl TryFrom<&JsonValue> for Timing {
 
 #[cfg(feature = "test_lib")]
 mod tests {
-    use crate::day;
+    use crate::{day, year};
 
     use super::{Timing, Timings};
 
@@ -187,42 +311,80 @@ mod tests {
         Timings {
             data: vec![
                 Timing {
+                    year: year!(2025),
                     day: day!(1),
+                    parse: None,
                     part_1: Some("10ms".into()),
                     part_2: Some("20ms".into()),
                     total_nanos: 3e+10,
+                    stats: [None, None, None],
                 },
                 Timing {
+                    year: year!(2025),
                     day: day!(2),
+                    parse: None,
                     part_1: Some("30ms".into()),
                     part_2: Some("40ms".into()),
                     total_nanos: 7e+10,
+                    stats: [None, None, None],
                 },
                 Timing {
+                    year: year!(2025),
                     day: day!(4),
+                    parse: None,
                     part_1: Some("40ms".into()),
                     part_2: None,
                     total_nanos: 4e+10,
+                    stats: [None, None, None],
                 },
             ],
         }
     }
 
     mod deserialization {
-        use crate::{day, template::timings::Timings};
+        use crate::template::stats::Statistic;
+        use crate::{day, template::timings::Timings, year};
 
         #[test]
         fn handles_json_timings() {
-            let json = r#"{ "data": [{ "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000000 }] }"#.to_string();
             let timings = Timings::try_from(json).unwrap();
             assert_eq!(timings.data.len(), 1);
             let timing = timings.data.first().unwrap();
+            assert_eq!(timing.year, year!(2025));
             assert_eq!(timing.day, day!(1));
             assert_eq!(timing.part_1, Some("1ms".to_string()));
             assert_eq!(timing.part_2, None);
             assert_eq!(timing.total_nanos, 1_000_000_000_f64);
         }
 
+        #[test]
+        fn handles_json_stats() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "01", "part_1": "1ms", "part_2": null, "total_nanos": 1000000,
+                "part_1_stats": { "mean_ns": 1000000, "median_ns": 900000, "stddev_ns": 10, "min_ns": 800000, "max_ns": 3000000, "p95_ns": 1200000, "outliers": 3 },
+                "part_2_stats": null }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            let timing = timings.data.first().unwrap();
+            assert!(timing.stats[0].is_none());
+            assert_eq!(timing.stats[1].unwrap().outliers, 3);
+            assert!(timing.stats[2].is_none());
+            assert_eq!(
+                timing.format_part(1, Statistic::Median),
+                Some("900.0µs".into())
+            );
+            assert_eq!(timing.format_part(1, Statistic::Mean), Some("1.0ms".into()));
+            assert_eq!(timing.format_part(2, Statistic::Median), None);
+        }
+
+        #[test]
+        fn skips_days_outside_the_event() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "13", "part_1": "1ms", "part_2": null, "total_nanos": 1 },
+                { "year": "2025", "day": "12", "part_1": "1ms", "part_2": null, "total_nanos": 1 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            assert_eq!(timings.data[0].day, day!(12));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let json = r#"{ "data": [] }"#.to_string();
@@ -271,49 +433,89 @@ mod tests {
     mod is_day_complete {
         use crate::{
             day,
+            template::PuzzleId,
             template::timings::{Timing, Timings},
+            year,
         };
 
         #[test]
         fn handles_completed_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2025),
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: Some("2ms".into()),
                     total_nanos: 3_000_000_000_f64,
+                    stats: [None, None, None],
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), true);
+            assert_eq!(
+                timings.is_day_complete(PuzzleId::new(year!(2025), day!(1)).unwrap()),
+                true
+            );
         }
 
         #[test]
         fn handles_partial_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2025),
                     day: day!(1),
+                    parse: None,
                     part_1: Some("1ms".into()),
                     part_2: None,
                     total_nanos: 1_000_000_000_f64,
+                    stats: [None, None, None],
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(PuzzleId::new(year!(2025), day!(1)).unwrap()),
+                false
+            );
+        }
+
+        #[test]
+        fn handles_last_day_with_one_part() {
+            let timings = Timings {
+                data: vec![Timing {
+                    year: year!(2025),
+                    day: day!(12),
+                    parse: None,
+                    part_1: Some("1ms".into()),
+                    part_2: None,
+                    total_nanos: 1_000_000_000_f64,
+                    stats: [None, None, None],
+                }],
+            };
+
+            assert_eq!(
+                timings.is_day_complete(PuzzleId::new(year!(2025), day!(12)).unwrap()),
+                true
+            );
         }
 
         #[test]
         fn handles_uncompleted_days() {
             let timings = Timings {
                 data: vec![Timing {
+                    year: year!(2025),
                     day: day!(1),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0.0,
+                    stats: [None, None, None],
                 }],
             };
 
-            assert_eq!(timings.is_day_complete(&day!(1)), false);
+            assert_eq!(
+                timings.is_day_complete(PuzzleId::new(year!(2025), day!(1)).unwrap()),
+                false
+            );
         }
     }
 
@@ -321,6 +523,7 @@ mod tests {
         use crate::{
             day,
             template::timings::{Timing, Timings},
+            year,
         };
 
         use super::get_mock_timings;
@@ -330,10 +533,13 @@ mod tests {
             let timings = get_mock_timings();
             let other = Timings {
                 data: vec![Timing {
+                    year: year!(2025),
                     day: day!(3),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    stats: [None, None, None],
                 }],
             };
             let merged = timings.merge(&other);
@@ -350,10 +556,13 @@ mod tests {
 
             let other = Timings {
                 data: vec![Timing {
+                    year: year!(2025),
                     day: day!(2),
+                    parse: None,
                     part_1: None,
                     part_2: None,
                     total_nanos: 0_f64,
+                    stats: [None, None, None],
                 }],
             };
             let merged = timings.merge(&other);
@@ -365,6 +574,38 @@ mod tests {
             assert_eq!(merged.data[2].day, day!(4));
         }
 
+        #[test]
+        fn keeps_same_day_of_different_years() {
+            let timings = get_mock_timings();
+            let other = Timings {
+                data: vec![Timing {
+                    year: year!(2024),
+                    day: day!(2),
+                    parse: None,
+                    part_1: None,
+                    part_2: None,
+                    total_nanos: 0_f64,
+                    stats: [None, None, None],
+                }],
+            };
+            let merged = timings.merge(&other);
+
+            assert_eq!(merged.data.len(), 4);
+            assert_eq!(merged.data[0].year, year!(2024));
+            assert_eq!(merged.data[0].day, day!(2));
+            assert_eq!(merged.data[2].year, year!(2025));
+            assert_eq!(merged.data[2].day, day!(2));
+        }
+
+        #[test]
+        fn skips_days_outside_the_event() {
+            let json = r#"{ "data": [{ "year": "2025", "day": "13", "part_1": "1ms", "part_2": null, "total_nanos": 1 },
+                { "year": "2025", "day": "12", "part_1": "1ms", "part_2": null, "total_nanos": 1 }] }"#.to_string();
+            let timings = Timings::try_from(json).unwrap();
+            assert_eq!(timings.data.len(), 1);
+            assert_eq!(timings.data[0].day, day!(12));
+        }
+
         #[test]
         fn handles_empty_timings() {
             let timings = Timings::default();
@@ -0,0 +1,213 @@
+use std::error::Error;
+use std::fmt::Display;
+use std::str::FromStr;
+
+use crate::template::{AllDays, Day};
+
+/// The first year Advent of Code was held.
+const FIRST_YEAR: u16 = 2015;
+
+/// The first year with a shortened event of 12 days.
+const FIRST_SHORT_YEAR: u16 = 2025;
+
+/// A valid year of advent (i.e. an integer in range 2015 to 9999).
+///
+/// # Display
+/// This value displays as a four digit number.
+///
+/// ```
+/// # use advent_of_code::template::Year;
+/// let year = Year::new(2025).unwrap();
+/// assert_eq!(year.to_string(), "2025")
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Year(u16);
+
+impl Year {
+    /// Creates a [`Year`] from the provided value if it's in the valid range,
+    /// returns [`None`] otherwise.
+    pub const fn new(year: u16) -> Option<Self> {
+        if year < FIRST_YEAR || year > 9999 {
+            return None;
+        }
+        Some(Self(year))
+    }
+
+    /// Converts the [`Year`] into an [`u16`].
+    pub fn into_inner(self) -> u16 {
+        self.0
+    }
+
+    /// Extracts the year from a solution binary name of the form `<year>_<day>`, e.g. `2025_01`.
+    pub const fn from_bin_name(name: &str) -> Option<Self> {
+        Self::parse_bin_name(name.as_bytes(), 0)
+    }
+
+    /// Extracts the year from the path of a solution source file, e.g. `src/bin/2025_01.rs`.
+    pub const fn from_source_path(path: &str) -> Option<Self> {
+        let bytes = path.as_bytes();
+        let mut start = bytes.len();
+        while start > 0 && bytes[start - 1] != b'/' && bytes[start - 1] != b'\\' {
+            start -= 1;
+        }
+        Self::parse_bin_name(bytes, start)
+    }
+
+    const fn parse_bin_name(bytes: &[u8], start: usize) -> Option<Self> {
+        if bytes.len() < start + 5 || bytes[start + 4] != b'_' {
+            return None;
+        }
+
+        let mut year: u16 = 0;
+        let mut i = start;
+        while i < start + 4 {
+            if !bytes[i].is_ascii_digit() {
+                return None;
+            }
+            year = year * 10 + (bytes[i] - b'0') as u16;
+            i += 1;
+        }
+
+        Self::new(year)
+    }
+
+    /// Reads the default year from the `AOC_YEAR` environment variable.
+    /// `.cargo/config.toml` sets this for all cargo aliases.
+    pub fn from_env() -> Option<Self> {
+        std::env::var("AOC_YEAR").ok()?.parse().ok()
+    }
+}
+
+impl Display for Year {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:04}", self.0)
+    }
+}
+
+impl PartialEq<u16> for Year {
+    fn eq(&self, other: &u16) -> bool {
+        self.0.eq(other)
+    }
+}
+
+/// The event calendar of a year.
+impl Year {
+    /// The last day of the event: the 25th until 2024, the 12th from 2025 on.
+    pub const fn last_day(self) -> Day {
+        let last_day = if self.0 < FIRST_SHORT_YEAR { 25 } else { 12 };
+        match Day::new(last_day) {
+            Some(day) => day,
+            None => unreachable!(),
+        }
+    }
+
+    /// Returns `true` if `day` is part of this year's event.
+    pub const fn contains(self, day: Day) -> bool {
+        day.into_inner() <= self.last_day().into_inner()
+    }
+
+    /// Number of puzzles on `day`. The last day of an event only has a first part.
+    pub const fn part_count(self, day: Day) -> u8 {
+        if day.into_inner() == self.last_day().into_inner() {
+            1
+        } else {
+            2
+        }
+    }
+
+    /// An iterator that yields every day of this year's event.
+    pub fn days(self) -> AllDays {
+        AllDays::until(self.last_day())
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+impl FromStr for Year {
+    type Err = YearFromStrError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let year = s.parse().map_err(|_| YearFromStrError)?;
+        Self::new(year).ok_or(YearFromStrError)
+    }
+}
+
+/// An error which can be returned when parsing a [`Year`].
+#[derive(Debug)]
+pub struct YearFromStrError;
+
+impl Error for YearFromStrError {}
+
+impl Display for YearFromStrError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("expecting a year of 2015 or later")
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+/// Creates a [`Year`] value in a const context.
+#[macro_export]
+macro_rules! year {
+    ($year:expr) => {
+        const {
+            $crate::template::Year::new($year)
+                .expect("invalid year, expecting a value of 2015 or later")
+        }
+    };
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::Year;
+    use crate::day;
+
+    #[test]
+    fn parses_years() {
+        assert_eq!("2025".parse::<Year>().ok(), Year::new(2025));
+        assert!("2014".parse::<Year>().is_err());
+        assert!("twenty".parse::<Year>().is_err());
+    }
+
+    #[test]
+    fn knows_event_length() {
+        assert_eq!(year!(2024).last_day(), day!(25));
+        assert_eq!(year!(2025).last_day(), day!(12));
+        assert!(year!(2025).contains(day!(12)));
+        assert!(!year!(2025).contains(day!(13)));
+        assert_eq!(year!(2025).days().count(), 12);
+        assert_eq!(year!(2015).days().count(), 25);
+    }
+
+    #[test]
+    fn last_day_has_a_single_part() {
+        assert_eq!(year!(2025).part_count(day!(11)), 2);
+        assert_eq!(year!(2025).part_count(day!(12)), 1);
+        assert_eq!(year!(2024).part_count(day!(12)), 2);
+        assert_eq!(year!(2024).part_count(day!(25)), 1);
+    }
+
+    #[test]
+    fn parses_bin_names() {
+        assert_eq!(Year::from_bin_name("2024_07"), Year::new(2024));
+        assert_eq!(Year::from_bin_name("07"), None);
+        assert_eq!(Year::from_bin_name("20x4_07"), None);
+        assert_eq!(Year::from_bin_name("1999_07"), None);
+    }
+
+    #[test]
+    fn parses_source_paths() {
+        assert_eq!(
+            Year::from_source_path("src/bin/2024_07.rs"),
+            Year::new(2024)
+        );
+        assert_eq!(
+            Year::from_source_path("C:\\aoc\\src\\bin\\2023_01.rs"),
+            Year::new(2023)
+        );
+        assert_eq!(Year::from_source_path("2022_01.rs"), Year::new(2022));
+        assert_eq!(Year::from_source_path("src/bin/solutions.rs"), None);
+    }
+}
@@ -0,0 +1,297 @@
+//! Disjoint sets (union-find) over indices, or over arbitrary keys with [`KeyedUnionFind`].
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// Disjoint sets of the elements `0..len`, merged by size with path compression.
+#[derive(Clone, Debug)]
+pub struct UnionFind {
+    parent: Vec<usize>,
+    /// Size of the component of every root. Stale for elements that are not roots.
+    size: Vec<usize>,
+    components: usize,
+}
+
+impl UnionFind {
+    /// Creates `len` singleton components.
+    pub fn new(len: usize) -> Self {
+        Self {
+            parent: (0..len).collect(),
+            size: vec![1; len],
+            components: len,
+        }
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.parent.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.parent.is_empty()
+    }
+
+    /// Adds a new singleton component, returns its element.
+    pub fn push(&mut self) -> usize {
+        let element = self.parent.len();
+        self.parent.push(element);
+        self.size.push(1);
+        self.components += 1;
+        element
+    }
+
+    /// The representative of the component of `element`.
+    ///
+    /// # Panics
+    /// Panics if `element` is out of bounds.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while self.parent[root] != root {
+            root = self.parent[root];
+        }
+
+        // point every element on the path straight at the root.
+        let mut current = element;
+        while self.parent[current] != root {
+            current = std::mem::replace(&mut self.parent[current], root);
+        }
+
+        root
+    }
+
+    /// Merges the components of `a` and `b`, returns `false` if they were already connected.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut a, mut b) = (self.find(a), self.find(b));
+        if a == b {
+            return false;
+        }
+        if self.size[a] < self.size[b] {
+            std::mem::swap(&mut a, &mut b);
+        }
+        self.parent[b] = a;
+        self.size[a] += self.size[b];
+        self.components -= 1;
+        true
+    }
+
+    pub fn connected(&mut self, a: usize, b: usize) -> bool {
+        self.find(a) == self.find(b)
+    }
+
+    /// Number of disjoint components.
+    #[inline]
+    pub fn component_count(&self) -> usize {
+        self.components
+    }
+
+    /// Number of elements in the component of `element`.
+    pub fn component_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.size[root]
+    }
+
+    /// Iterate over the members of every component, ordered by their smallest element.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<usize>> {
+        let mut index_of_root = HashMap::with_capacity(self.components);
+        let mut components: Vec<Vec<usize>> = Vec::with_capacity(self.components);
+
+        for element in 0..self.len() {
+            let root = self.find(element);
+            let index = *index_of_root.entry(root).or_insert_with(|| {
+                components.push(Vec::with_capacity(self.size[root]));
+                components.len() - 1
+            });
+            components[index].push(element);
+        }
+
+        components.into_iter()
+    }
+}
+
+/// A [`UnionFind`] over keys, which are mapped to indices as they are first seen.
+#[derive(Clone, Debug)]
+pub struct KeyedUnionFind<K> {
+    sets: UnionFind,
+    keys: Vec<K>,
+    indices: HashMap<K, usize>,
+}
+
+impl<K> Default for KeyedUnionFind<K> {
+    fn default() -> Self {
+        Self {
+            sets: UnionFind::new(0),
+            keys: vec![],
+            indices: HashMap::new(),
+        }
+    }
+}
+
+impl<K: Clone + Eq + Hash> KeyedUnionFind<K> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+
+    /// Adds `key` as a singleton component if it is new, returns its index.
+    pub fn insert(&mut self, key: K) -> usize {
+        if let Some(&index) = self.indices.get(&key) {
+            return index;
+        }
+        let index = self.sets.push();
+        self.keys.push(key.clone());
+        self.indices.insert(key, index);
+        index
+    }
+
+    /// Index of `key` in the underlying [`UnionFind`].
+    pub fn index_of(&self, key: &K) -> Option<usize> {
+        self.indices.get(key).copied()
+    }
+
+    /// The key of `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    pub fn key(&self, index: usize) -> &K {
+        &self.keys[index]
+    }
+
+    /// The representative key of the component of `key`, `None` if `key` is unknown.
+    pub fn find(&mut self, key: &K) -> Option<&K> {
+        let index = self.index_of(key)?;
+        Some(&self.keys[self.sets.find(index)])
+    }
+
+    /// Merges the components of `a` and `b`, inserting unknown keys first.
+    /// Returns `false` if they were already connected.
+    pub fn union(&mut self, a: &K, b: &K) -> bool {
+        let a = self.insert(a.clone());
+        let b = self.insert(b.clone());
+        self.sets.union(a, b)
+    }
+
+    /// Whether `a` and `b` are known and part of the same component.
+    pub fn connected(&mut self, a: &K, b: &K) -> bool {
+        match (self.index_of(a), self.index_of(b)) {
+            (Some(a), Some(b)) => self.sets.connected(a, b),
+            _ => false,
+        }
+    }
+
+    /// Number of disjoint components.
+    #[inline]
+    pub fn component_count(&self) -> usize {
+        self.sets.component_count()
+    }
+
+    /// Number of keys in the component of `key`, `None` if `key` is unknown.
+    pub fn component_size(&mut self, key: &K) -> Option<usize> {
+        let index = self.index_of(key)?;
+        Some(self.sets.component_size(index))
+    }
+
+    /// Iterate over the members of every component, ordered by the insertion of their first key.
+    pub fn components(&mut self) -> impl Iterator<Item = Vec<&K>> {
+        let keys = &self.keys;
+        self.sets
+            .components()
+            .map(move |members| members.into_iter().map(|index| &keys[index]).collect())
+    }
+}
+
+impl<K: Clone + Eq + Hash> FromIterator<K> for KeyedUnionFind<K> {
+    fn from_iter<I: IntoIterator<Item = K>>(iter: I) -> Self {
+        let mut sets = Self::new();
+        for key in iter {
+            sets.insert(key);
+        }
+        sets
+    }
+}
+
+/* -------------------------------------------------------------------------- */
+
+#[cfg(feature = "test_lib")]
+mod tests {
+    use super::{KeyedUnionFind, UnionFind};
+
+    #[test]
+    fn merges_components() {
+        let mut sets = UnionFind::new(6);
+        assert_eq!(sets.component_count(), 6);
+
+        assert!(sets.union(0, 1));
+        assert!(sets.union(2, 1));
+        assert!(!sets.union(0, 2));
+        assert!(sets.union(4, 5));
+
+        assert_eq!(sets.component_count(), 3);
+        assert!(sets.connected(0, 2));
+        assert!(!sets.connected(0, 3));
+        assert_eq!(sets.component_size(1), 3);
+        assert_eq!(sets.component_size(3), 1);
+    }
+
+    #[test]
+    fn lists_components() {
+        let mut sets = UnionFind::new(5);
+        sets.union(4, 1);
+        sets.union(3, 0);
+        assert_eq!(
+            sets.components().collect::<Vec<_>>(),
+            vec![vec![0, 3], vec![1, 4], vec![2]]
+        );
+    }
+
+    #[test]
+    fn handles_long_chains() {
+        let len = 1_000_000;
+        let mut sets = UnionFind::new(len);
+        // union by size never builds a chain, so link one by hand. A recursive find would overflow the stack here.
+        for i in 1..len {
+            sets.parent[i - 1] = i;
+        }
+        assert_eq!(sets.find(0), len - 1);
+        assert_eq!(sets.parent[len / 2], len - 1);
+    }
+
+    #[test]
+    fn grows_with_push() {
+        let mut sets = UnionFind::new(1);
+        let element = sets.push();
+        assert_eq!(element, 1);
+        assert_eq!(sets.len(), 2);
+        assert_eq!(sets.component_count(), 2);
+        sets.union(0, element);
+        assert_eq!(sets.component_count(), 1);
+    }
+
+    #[test]
+    fn maps_keys_to_indices() {
+        let mut sets: KeyedUnionFind<&str> = ["a", "b", "c"].into_iter().collect();
+        assert!(sets.union(&"a", &"c"));
+        assert!(sets.union(&"d", &"b"));
+
+        assert_eq!(sets.len(), 4);
+        assert_eq!(sets.index_of(&"d"), Some(3));
+        assert_eq!(sets.key(3), &"d");
+        assert_eq!(sets.component_count(), 2);
+        assert!(sets.connected(&"b", &"d"));
+        assert!(!sets.connected(&"a", &"x"));
+        assert_eq!(sets.component_size(&"c"), Some(2));
+        assert_eq!(sets.find(&"x"), None);
+        assert_eq!(
+            sets.components().collect::<Vec<_>>(),
+            vec![vec![&"a", &"c"], vec![&"b", &"d"]]
+        );
+    }
+}